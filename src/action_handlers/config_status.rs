@@ -0,0 +1,21 @@
+use crate::{
+	models::{ConfigStatus, RSState},
+	ui::widgets::HelpWidget,
+	STYLES,
+};
+
+pub fn handle(msg: &ConfigStatus, state: &mut RSState) {
+	match msg {
+		ConfigStatus::Reloaded => {
+			state.config_error = None;
+			state.ui.buffer.set_styles(STYLES.read().unwrap().clone());
+			state.help = HelpWidget::default();
+			state.load_scenes();
+		}
+		ConfigStatus::Error(err) => {
+			state.config_error = Some(err.clone());
+		}
+	}
+
+	state.redraw.resize = true;
+}
@@ -0,0 +1,77 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::models::{RSState, UserAction};
+
+pub fn handle(actions: &mut Vec<UserAction>, input: &KeyEvent, state: &RSState) {
+	if let Some((value, cursor)) = edit(input, &state.filter.value, state.filter.cursor) {
+		actions.clear();
+		actions.push(UserAction::ChangeFilterValue(value, cursor));
+		return;
+	}
+
+	keep_confirm_or_close(actions);
+}
+
+// every printable key is a part of the text, even if it's bound to something else
+pub fn edit(input: &KeyEvent, value: &str, cursor: u8) -> Option<(String, u8)> {
+	let typing = !input
+		.modifiers
+		.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT);
+
+	match input.code {
+		KeyCode::Char(x) if typing => Some(add_char(x, value, cursor)),
+		KeyCode::Backspace => Some(remove_char(value, cursor)),
+		KeyCode::Left => Some(move_cursor(value, cursor, -1)),
+		KeyCode::Right => Some(move_cursor(value, cursor, 1)),
+		_ => None,
+	}
+}
+
+pub fn keep_confirm_or_close(actions: &mut Vec<UserAction>) {
+	let confirm = actions.contains(&UserAction::Confirm);
+	let close_context_menu = actions.contains(&UserAction::CloseContextMenu);
+
+	if confirm {
+		actions.clear();
+		actions.push(UserAction::Confirm);
+	} else if close_context_menu {
+		actions.clear();
+		actions.push(UserAction::CloseContextMenu);
+	}
+}
+
+fn remove_char(value: &str, cursor: u8) -> (String, u8) {
+	let mut value = value.chars().collect::<Vec<char>>();
+	let cursor = cursor as usize;
+
+	if cursor == 0 {
+		(value.into_iter().collect(), 0)
+	} else {
+		value.remove(cursor - 1);
+
+		(value.into_iter().collect(), cursor as u8 - 1)
+	}
+}
+
+fn move_cursor(value: &str, cursor: u8, val: i8) -> (String, u8) {
+	let new_cursor = cursor as i16 + val as i16;
+
+	if new_cursor < 0 || new_cursor > value.chars().count() as i16 {
+		(value.to_string(), cursor)
+	} else {
+		(value.to_string(), new_cursor as u8)
+	}
+}
+
+fn add_char(c: char, value: &str, cursor: u8) -> (String, u8) {
+	let mut value = value.chars().collect::<Vec<char>>();
+	let cursor = cursor as usize;
+
+	if value.len() == u8::MAX as usize {
+		(value.into_iter().collect(), cursor as u8)
+	} else {
+		value.insert(cursor, c);
+
+		(value.into_iter().collect(), cursor as u8 + 1)
+	}
+}
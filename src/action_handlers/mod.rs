@@ -1,5 +1,42 @@
+pub mod config_status;
+pub mod filter_input_edit;
+pub mod module_input_edit;
 pub mod pulseaudio_info;
 pub mod pulseaudio_status;
 pub mod user_action;
 pub mod user_input;
 pub mod volume_input_edit;
+
+use std::time::Instant;
+
+use crate::{
+	actor_system::prelude::*,
+	models::{
+		ConfigStatus, EntryUpdate, PAStatus, RSState, ResizeScreen, ToastExpired, UserAction,
+		UserInput,
+	},
+	prelude::*,
+};
+
+// passes a message received by the event loop to its handler
+pub fn handle(msg: &BoxedMessage, state: &mut RSState, ctx: &Ctx) -> Result<()> {
+	if let Some(msg) = msg.downcast_ref::<EntryUpdate>() {
+		pulseaudio_info::handle(msg, state);
+	} else if let Some(msg) = msg.downcast_ref::<PAStatus>() {
+		pulseaudio_status::handle(msg, state);
+	} else if let Some(msg) = msg.downcast_ref::<UserInput>() {
+		user_input::handle(msg, state, ctx)?;
+	} else if let Some(msg) = msg.downcast_ref::<UserAction>() {
+		user_action::handle(msg, state, ctx);
+		// everything sent to pulseaudio for one user action is undone at once
+		state.history.commit(Instant::now());
+	} else if let Some(msg) = msg.downcast_ref::<ConfigStatus>() {
+		config_status::handle(msg, state);
+	} else if let Some(msg) = msg.downcast_ref::<ToastExpired>() {
+		state.hide_toast(msg.0);
+	} else if msg.is::<ResizeScreen>() {
+		state.redraw.resize = true;
+	}
+
+	Ok(())
+}
@@ -0,0 +1,15 @@
+use crossterm::event::KeyEvent;
+
+use super::filter_input_edit::{edit, keep_confirm_or_close};
+use crate::models::{RSState, UserAction};
+
+pub fn handle(actions: &mut Vec<UserAction>, input: &KeyEvent, state: &RSState) {
+	if let Some((value, cursor)) = edit(input, &state.module_input.value, state.module_input.cursor)
+	{
+		actions.clear();
+		actions.push(UserAction::ChangeModuleInputValue(value, cursor));
+		return;
+	}
+
+	keep_confirm_or_close(actions);
+}
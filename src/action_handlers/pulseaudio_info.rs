@@ -7,11 +7,24 @@ pub fn handle(msg: &EntryUpdate, state: &mut RSState) {
 		EntryUpdate::EntryUpdate(ident, entry) => {
 			state.update_entry(ident, entry.deref().to_owned());
 		}
+		EntryUpdate::EntryCreated(ident) => {
+			state.new_entries.insert(*ident);
+		}
+		EntryUpdate::EntryChanged(ident) => {
+			state.entry_changed(ident);
+		}
 		EntryUpdate::EntryRemoved(ident) => {
 			state.remove_entry(&ident);
 		}
-		EntryUpdate::PeakVolumeUpdate(ident, peak) => {
-			state.update_peak_volume(ident, peak);
+		EntryUpdate::PeakVolumeUpdate(ident, levels) => {
+			state.update_peak_volume(ident, levels);
+		}
+		EntryUpdate::DefaultChanged(ident) => {
+			state.update_default(ident);
+		}
+		EntryUpdate::ModuleLoaded(ident) => {
+			state.module_loaded(ident);
 		}
+		EntryUpdate::Synced => {}
 	}
 }
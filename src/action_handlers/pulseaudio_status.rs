@@ -1,14 +1,16 @@
-use crate::models::{PAStatus, RSState, UIMode};
+use crate::models::{Event, EventKind, PAStatus, RSState, UIMode};
 
 pub fn handle(msg: &PAStatus, state: &mut RSState) {
 	match msg {
 		PAStatus::PulseAudioDisconnected => {
+			state.log_event(Event::connection(EventKind::Disconnected));
 			state.reset();
 		}
 		PAStatus::RetryIn(time) => {
 			state.change_ui_mode(UIMode::RetryIn(*time));
 		}
 		PAStatus::ConnectToPulseAudio => {
+			state.log_event(Event::connection(EventKind::Connecting));
 			state.change_ui_mode(UIMode::Normal);
 		}
 	}
@@ -18,7 +18,7 @@ pub fn handle(msg: &UserAction, state: &mut RSState, ctx: &Ctx) {
 			state.move_right();
 		}
 		UserAction::SetSelected(index) => {
-			state.set_selected(*index as usize);
+			state.set_selected(*index);
 		}
 		UserAction::ChangePage(page) => {
 			state.change_page(*page);
@@ -30,14 +30,14 @@ pub fn handle(msg: &UserAction, state: &mut RSState, ctx: &Ctx) {
 			);
 		}
 		UserAction::RequestMute(ident) => {
-			if state.ui_mode != UIMode::Normal || state.current_page == PageType::Cards {
+			if state.ui_mode != UIMode::Normal || !state.current_page.has_volume() {
 				return;
 			}
 
 			state.request_mute(ident);
 		}
 		UserAction::RequstChangeVolume(how_much, ident) => {
-			if state.ui_mode != UIMode::Normal || state.current_page == PageType::Cards {
+			if state.ui_mode != UIMode::Normal || !state.current_page.has_volume() {
 				return;
 			}
 
@@ -48,26 +48,71 @@ pub fn handle(msg: &UserAction, state: &mut RSState, ctx: &Ctx) {
 				state.open_context_menu(ident);
 			}
 		}
-		UserAction::CloseContextMenu => {
-			if let UIMode::ContextMenu | UIMode::Help | UIMode::InputVolumeValue = state.ui_mode {
+		UserAction::CloseContextMenu => match state.ui_mode {
+			UIMode::ContextMenu
+			| UIMode::Help
+			| UIMode::Details
+			| UIMode::InputVolumeValue
+			| UIMode::Channels
+			| UIMode::Message
+			| UIMode::LoadModule
+			| UIMode::NameNullSink => {
 				state.change_ui_mode(UIMode::Normal);
 			}
-		}
+			UIMode::PickEntries(_) => {
+				state.stop_picking();
+			}
+			UIMode::Filter => {
+				state.change_ui_mode(UIMode::Normal);
+				state.set_filter_value(String::new(), 0);
+			}
+			UIMode::Normal if !state.filter.value.is_empty() => {
+				state.set_filter_value(String::new(), 0);
+			}
+			UIMode::Normal if !state.marked.is_empty() => {
+				state.clear_marked();
+			}
+			_ => {}
+		},
 		UserAction::Confirm => match state.ui_mode {
 			UIMode::ContextMenu => {
 				state.confirm_context_menu();
 			}
 			UIMode::MoveEntry(ident, parent) => {
 				state.change_ui_mode(UIMode::Normal);
-				ctx.send_to(
-					"pulseaudio",
-					PulseAudioAction::MoveEntryToParent(ident, parent),
-				);
+				// the marked entries are moved instead of the one the menu was opened for
+				let ident = if state.marked.is_empty() {
+					Some(ident)
+				} else {
+					None
+				};
+				let (_, child_type) = state.current_page.parent_child_types();
+				for member in state
+					.targets(&ident)
+					.into_iter()
+					.filter(|i| i.entry_type == child_type)
+				{
+					state.dispatch(PulseAudioAction::MoveEntryToParent(member, parent));
+				}
 			}
 			UIMode::InputVolumeValue => {
 				state.confirm_input_volume();
 				state.change_ui_mode(UIMode::Normal);
 			}
+			UIMode::LoadModule => {
+				state.confirm_load_module();
+				state.change_ui_mode(UIMode::Normal);
+			}
+			UIMode::NameNullSink => {
+				state.confirm_null_sink();
+				state.change_ui_mode(UIMode::Normal);
+			}
+			UIMode::PickEntries(device) => {
+				state.confirm_picking(device);
+			}
+			UIMode::Filter | UIMode::Message | UIMode::Details => {
+				state.change_ui_mode(UIMode::Normal);
+			}
 			_ => {}
 		},
 		UserAction::Hide(ident) => {
@@ -80,15 +125,112 @@ pub fn handle(msg: &UserAction, state: &mut RSState, ctx: &Ctx) {
 				state.change_ui_mode(UIMode::Help);
 			}
 		}
+		UserAction::Undo => {
+			if UIMode::Normal == state.ui_mode {
+				state.undo();
+			}
+		}
+		UserAction::Redo => {
+			if UIMode::Normal == state.ui_mode {
+				state.redo();
+			}
+		}
 		UserAction::RequestQuit => {
 			ctx.shutdown();
 		}
 		UserAction::InputVolumeValue => {
-			if UIMode::Normal == state.ui_mode && state.current_page != PageType::Cards {
+			if UIMode::Normal == state.ui_mode && state.current_page.has_volume() {
 				state.setup_volume_input();
 				state.change_ui_mode(UIMode::InputVolumeValue);
 			}
 		}
+		UserAction::ShowChannels => {
+			if UIMode::Normal == state.ui_mode && state.current_page.has_volume() {
+				state.open_channels();
+			}
+		}
+		UserAction::ShowDetails => {
+			if UIMode::Normal == state.ui_mode && state.current_page.has_volume() {
+				state.open_details();
+			}
+		}
+		UserAction::RequestChangeChannelVolume(how_much) => {
+			if state.ui_mode == UIMode::Channels {
+				state.request_change_channel_volume(*how_much);
+			}
+		}
+		UserAction::RequestChangeBalance(how_much) => {
+			if let UIMode::Normal | UIMode::Channels = state.ui_mode {
+				if state.current_page.has_volume() {
+					state.request_change_balance(*how_much);
+				}
+			}
+		}
+		UserAction::ToggleChannelLock => {
+			if state.ui_mode == UIMode::Channels {
+				state.channels.locked = !state.channels.locked;
+				state.redraw.context_menu = true;
+			}
+		}
+		UserAction::OpenFilter => {
+			if UIMode::Normal == state.ui_mode {
+				state.change_ui_mode(UIMode::Filter);
+			}
+		}
+		UserAction::ChangeFilterValue(value, cursor) => {
+			state.set_filter_value(value.clone(), *cursor);
+		}
+		UserAction::LoadModule => {
+			if let UIMode::Normal | UIMode::ContextMenu = state.ui_mode {
+				state.open_module_input();
+			}
+		}
+		UserAction::ChangeModuleInputValue(value, cursor) => {
+			if let UIMode::LoadModule | UIMode::NameNullSink = state.ui_mode {
+				state.set_module_input_value(value.clone(), *cursor);
+			}
+		}
+		UserAction::CreateNullSink => {
+			if let UIMode::Normal | UIMode::ContextMenu = state.ui_mode {
+				if state.current_page == PageType::Output {
+					state.open_null_sink_input();
+				}
+			}
+		}
+		UserAction::StartPicking(device) => {
+			if let UIMode::Normal | UIMode::ContextMenu = state.ui_mode {
+				if state.current_page == PageType::Output {
+					state.start_picking(*device);
+				}
+			}
+		}
+		UserAction::TogglePicked => {
+			if let UIMode::PickEntries(device) = state.ui_mode {
+				if device.picks_many() {
+					state.toggle_picked();
+				}
+			}
+		}
+		UserAction::ToggleMarked => {
+			if state.ui_mode == UIMode::Normal && state.current_page.has_volume() {
+				state.toggle_marked();
+			}
+		}
+		UserAction::UnloadCreatedModules => {
+			if let UIMode::Normal | UIMode::ContextMenu = state.ui_mode {
+				state.unload_created_modules();
+			}
+		}
+		UserAction::ApplyScene(name) => {
+			if let UIMode::Normal | UIMode::Message = state.ui_mode {
+				state.apply_scene(name);
+			}
+		}
+		UserAction::SaveScene(name) => {
+			if let UIMode::Normal | UIMode::Message = state.ui_mode {
+				state.save_scene(name);
+			}
+		}
 		UserAction::ChangeVolumeInputValue(value, cursor) => {
 			state.set_volume_input_value(value.clone(), *cursor);
 		}
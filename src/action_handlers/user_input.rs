@@ -3,10 +3,10 @@ use std::convert::TryFrom;
 use anyhow::Result;
 use crossterm::event::{Event, MouseButton, MouseEvent, MouseEventKind};
 
-use super::volume_input_edit;
+use super::{filter_input_edit, module_input_edit, volume_input_edit};
 use crate::{
 	actor_system::Ctx,
-	entry::{EntryIdentifier, EntryKind},
+	entry::EntryIdentifier,
 	models::{InputEvent, PageType, RSState, UIMode, UserAction, UserInput},
 	ui::{Rect, Scrollable},
 	BINDINGS,
@@ -16,7 +16,7 @@ pub fn handle(input: &UserInput, state: &RSState, ctx: &Ctx) -> Result<()> {
 	let input_event = InputEvent::try_from(input.event)?;
 	let mut actions;
 
-	if let Some(bindings) = (*BINDINGS).get().get_vec(&input_event) {
+	if let Some(bindings) = BINDINGS.read().unwrap().get_vec(&input_event) {
 		actions = bindings.clone();
 
 		handle_conflicting_bindings(&mut actions, state);
@@ -38,6 +38,18 @@ pub fn handle(input: &UserInput, state: &RSState, ctx: &Ctx) -> Result<()> {
 		}
 	}
 
+	if state.ui_mode == UIMode::Filter {
+		if let Event::Key(key_event) = input.event {
+			filter_input_edit::handle(&mut actions, &key_event, state);
+		}
+	}
+
+	if let UIMode::LoadModule | UIMode::NameNullSink = state.ui_mode {
+		if let Event::Key(key_event) = input.event {
+			module_input_edit::handle(&mut actions, &key_event, state);
+		}
+	}
+
 	for action in actions {
 		ctx.send_to("event_loop", action);
 	}
@@ -57,6 +69,25 @@ fn handle_unbindable_mouse_actions(
 				actions.push(UserAction::CloseContextMenu);
 			}
 		}
+		(UIMode::Details, MouseEventKind::Up(_))
+			if !mouse_pos.intersects(&state.details.window.area) =>
+		{
+			actions.push(UserAction::CloseContextMenu);
+		}
+		(UIMode::Channels, MouseEventKind::Up(_))
+			if !mouse_pos.intersects(&state.channels.window.area) =>
+		{
+			actions.push(UserAction::CloseContextMenu);
+		}
+		(UIMode::Message, MouseEventKind::Up(_)) => {
+			actions.push(UserAction::CloseContextMenu);
+		}
+		(UIMode::LoadModule, MouseEventKind::Up(_))
+		| (UIMode::NameNullSink, MouseEventKind::Up(_))
+			if !mouse_pos.intersects(&state.module_input.window.area) =>
+		{
+			actions.push(UserAction::CloseContextMenu);
+		}
 		(UIMode::Normal, MouseEventKind::Up(MouseButton::Left)) => {
 			let (ident, page_type) = find_collisions(mouse_event, state);
 
@@ -138,12 +169,7 @@ fn find_collisions(
 			.filter_map(|i| state.page_entries.get(i))
 			.filter_map(|ident| state.entries.get(&ident))
 		{
-			let area = match &entry.entry_kind {
-				EntryKind::CardEntry(card) => card.area,
-				EntryKind::PlayEntry(play) => play.area,
-			};
-
-			if area.intersects(&mouse_event_rect) {
+			if entry.area().intersects(&mouse_event_rect) {
 				ident = Some(EntryIdentifier::new(entry.entry_type, entry.index));
 				break;
 			}
@@ -196,8 +222,13 @@ fn handle_conflicting_bindings(actions: &mut Vec<UserAction>, state: &RSState) {
 	{
 		if let UIMode::ContextMenu
 		| UIMode::Help
+		| UIMode::Details
 		| UIMode::InputVolumeValue
-		| UIMode::MoveEntry(_, _) = state.ui_mode
+		| UIMode::Message
+		| UIMode::LoadModule
+		| UIMode::NameNullSink
+		| UIMode::MoveEntry(_, _)
+		| UIMode::PickEntries(_) = state.ui_mode
 		{
 			actions.retain(|action| *action != UserAction::RequestQuit);
 		} else {
@@ -208,8 +239,14 @@ fn handle_conflicting_bindings(actions: &mut Vec<UserAction>, state: &RSState) {
 	if actions.contains(&UserAction::Confirm)
 		&& actions.contains(&UserAction::OpenContextMenu(None))
 	{
-		if let UIMode::MoveEntry(_, _) | UIMode::ContextMenu | UIMode::InputVolumeValue =
-			state.ui_mode
+		if let UIMode::MoveEntry(_, _)
+		| UIMode::ContextMenu
+		| UIMode::Details
+		| UIMode::InputVolumeValue
+		| UIMode::Message
+		| UIMode::LoadModule
+		| UIMode::NameNullSink
+		| UIMode::PickEntries(_) = state.ui_mode
 		{
 			actions.retain(|action| *action != UserAction::OpenContextMenu(None));
 		} else {
@@ -217,8 +254,19 @@ fn handle_conflicting_bindings(actions: &mut Vec<UserAction>, state: &RSState) {
 		}
 	}
 
+	if actions.contains(&UserAction::TogglePicked) && actions.contains(&UserAction::ToggleMarked) {
+		if let UIMode::PickEntries(_) = state.ui_mode {
+			actions.retain(|action| *action != UserAction::ToggleMarked);
+		} else {
+			actions.retain(|action| *action != UserAction::TogglePicked);
+		}
+	}
+
+	// left and right change the filter of the log page
+	let on_log = state.ui_mode == UIMode::Normal && state.current_page == PageType::Log;
+
 	if actions.contains(&UserAction::MoveLeft) {
-		if let UIMode::ContextMenu | UIMode::Help = state.ui_mode {
+		if on_log || matches!(state.ui_mode, UIMode::ContextMenu | UIMode::Help) {
 			actions.retain(|action| *action == UserAction::MoveLeft);
 		} else {
 			actions.retain(|action| *action != UserAction::MoveLeft);
@@ -226,7 +274,7 @@ fn handle_conflicting_bindings(actions: &mut Vec<UserAction>, state: &RSState) {
 	}
 
 	if actions.contains(&UserAction::MoveRight) {
-		if let UIMode::ContextMenu | UIMode::Help = state.ui_mode {
+		if on_log || matches!(state.ui_mode, UIMode::ContextMenu | UIMode::Help) {
 			actions.retain(|action| *action == UserAction::MoveRight);
 		} else {
 			actions.retain(|action| *action != UserAction::MoveRight);
@@ -3,6 +3,9 @@ use crossterm::event::{KeyCode, KeyEvent};
 
 use crate::models::{RSState, UserAction};
 
+// enough for "-100.0dB"
+const MAX_LEN: usize = 8;
+
 pub fn handle(actions: &mut Vec<UserAction>, input: &KeyEvent, state: &RSState) -> Result<()> {
 	let confirm = actions.iter().any(|a| *a == UserAction::Confirm);
 	let close_context_menu = actions.iter().any(|a| *a == UserAction::CloseContextMenu);
@@ -28,7 +31,22 @@ pub fn handle(actions: &mut Vec<UserAction>, input: &KeyEvent, state: &RSState)
 		| KeyCode::Char(x @ '6')
 		| KeyCode::Char(x @ '7')
 		| KeyCode::Char(x @ '8')
-		| KeyCode::Char(x @ '9') => Some(add_char(x, state)),
+		| KeyCode::Char(x @ '9')
+		| KeyCode::Char(x @ '.')
+		| KeyCode::Char(x @ '-')
+		| KeyCode::Char(x @ '%')
+		| KeyCode::Char(x @ 'd')
+		| KeyCode::Char(x @ 'D')
+		| KeyCode::Char(x @ 'b')
+		| KeyCode::Char(x @ 'B')
+		// for "-inf dB", what a muted entry starts with
+		| KeyCode::Char(x @ 'i')
+		| KeyCode::Char(x @ 'I')
+		| KeyCode::Char(x @ 'n')
+		| KeyCode::Char(x @ 'N')
+		| KeyCode::Char(x @ 'f')
+		| KeyCode::Char(x @ 'F')
+		| KeyCode::Char(x @ ' ') => Some(add_char(x, state)),
 		KeyCode::Backspace => Some(remove_char(state)),
 		KeyCode::Left => Some(move_cursor(state, -1)),
 		KeyCode::Right => Some(move_cursor(state, 1)),
@@ -71,7 +89,7 @@ fn add_char(c: char, state: &RSState) -> (String, u8) {
 	let value = state.input_exact_volume.value.clone();
 	let cursor = state.input_exact_volume.cursor as usize;
 
-	if value.len() == 3 {
+	if value.len() == MAX_LEN {
 		(value, cursor as u8)
 	} else {
 		let value = format!("{}{}{}", &value[0..cursor], c, &value[cursor..]);
@@ -0,0 +1,76 @@
+use std::{fs, path::Path, time::SystemTime};
+
+use anyhow::Result;
+use tokio_stream::{wrappers::IntervalStream, StreamExt};
+
+use crate::{actor_system::prelude::*, config::RsMixerConfig, models::ConfigStatus};
+
+// checks every second whether the config file was modified
+pub struct ConfigWatcherActor {}
+
+impl ConfigWatcherActor {
+	pub fn factory() -> Actor {
+		Actor::Continous(Box::new(Self {}))
+	}
+
+	pub fn item() -> ActorItem {
+		ActorItem::new("config_watcher", &Self::factory)
+			.on_panic(|_| -> PinnedClosure { Box::pin(async { true }) })
+			.on_error(|_| -> PinnedClosure { Box::pin(async { true }) })
+	}
+}
+
+#[async_trait]
+impl ContinousActor for ConfigWatcherActor {
+	async fn start(&mut self, _ctx: Ctx) {}
+	async fn stop(&mut self) {}
+
+	fn run(&mut self, ctx: Ctx, events_rx: LockedReceiver) -> BoxedResultFuture {
+		Box::pin(start(events_rx, ctx))
+	}
+}
+
+pub async fn start(rx: LockedReceiver, ctx: Ctx) -> Result<()> {
+	let path = match RsMixerConfig::path() {
+		Some(path) => path,
+		None => {
+			return Ok(());
+		}
+	};
+
+	let mut interval =
+		IntervalStream::new(tokio::time::interval(std::time::Duration::from_secs(1)));
+	let mut rx = rx.write().await;
+
+	let mut last_modified = modified(&path);
+
+	loop {
+		tokio::select! {
+			_ = interval.next() => {
+				let current = modified(&path);
+				if current == last_modified {
+					continue;
+				}
+
+				let status = match RsMixerConfig::reload() {
+					Ok(()) => ConfigStatus::Reloaded,
+					Err(err) => ConfigStatus::Error(format!("{:#}", err).replace('\n', " ")),
+				};
+				ctx.send_to("event_loop", status);
+
+				// reloading can upgrade and rewrite the file
+				last_modified = modified(&path);
+			}
+			ev = rx.next() => {
+				let ev = if let Some(ev) = ev { ev } else { continue; };
+				if ev.is::<Shutdown>() {
+					return Ok(());
+				}
+			}
+		};
+	}
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+	fs::metadata(path).and_then(|m| m.modified()).ok()
+}
@@ -4,11 +4,9 @@ use anyhow::Result;
 use futures::Future;
 
 use crate::{
-	action_handlers::*,
+	action_handlers,
 	actor_system::prelude::*,
-	models::{
-		EntryUpdate, PAStatus, PulseAudioAction, RSState, ResizeScreen, UserAction, UserInput,
-	},
+	models::{PulseAudioAction, RSState},
 	ui, STYLES,
 };
 
@@ -35,7 +33,11 @@ impl EventfulActor for EventLoopActor {
 	async fn start(&mut self, ctx: Ctx) {
 		self.stdout = Some(ui::prepare_terminal().unwrap());
 		self.state = RSState::new(ctx.clone());
-		self.state.ui.buffer.set_styles((*STYLES).get().clone());
+		self.state.load_scenes();
+		self.state
+			.ui
+			.buffer
+			.set_styles(STYLES.read().unwrap().clone());
 		self.state.redraw.resize = true;
 
 		ctx.send_to("pulseaudio", PulseAudioAction::RequestPulseAudioState);
@@ -51,25 +53,7 @@ impl EventfulActor for EventLoopActor {
 		msg: BoxedMessage,
 	) -> Pin<Box<dyn Future<Output = Result<()>> + Send + Sync + 'a>> {
 		Box::pin(async move {
-			if msg.is::<EntryUpdate>() {
-				let msg = msg.downcast_ref::<EntryUpdate>().unwrap();
-
-				pulseaudio_info::handle(&msg, &mut self.state);
-			} else if msg.is::<PAStatus>() {
-				let msg = msg.downcast_ref::<PAStatus>().unwrap();
-
-				pulseaudio_status::handle(&msg, &mut self.state);
-			} else if msg.is::<UserInput>() {
-				let msg = msg.downcast_ref::<UserInput>().unwrap();
-
-				user_input::handle(&msg, &self.state, &ctx)?;
-			} else if msg.is::<UserAction>() {
-				let msg = msg.downcast_ref::<UserAction>().unwrap();
-
-				user_action::handle(&msg, &mut self.state, &ctx);
-			} else if msg.is::<ResizeScreen>() {
-				self.state.redraw.resize = true;
-			}
+			action_handlers::handle(&msg, &mut self.state, &ctx)?;
 
 			if self.state.redraw.anything() {
 				if let Some(stdout) = &mut self.stdout {
@@ -1,7 +1,9 @@
+mod config_watcher_actor;
 mod event_loop_actor;
 mod input_actor;
 mod pa_actor;
 
+pub use config_watcher_actor::ConfigWatcherActor;
 pub use event_loop_actor::EventLoopActor;
 pub use input_actor::InputActor;
 pub use pa_actor::PulseActor;
@@ -45,7 +45,6 @@ async fn start_async(external_rx: LockedReceiver, ctx: Ctx) -> Result<()> {
 			Err(err) => Err(PAError::ChannelError(err).into()),
 		}
 	};
-	let retry_time = (*VARIABLES).get().pa_retry_time;
 	let mut external_rx = external_rx.write().await;
 
 	loop {
@@ -107,6 +106,8 @@ async fn start_async(external_rx: LockedReceiver, ctx: Ctx) -> Result<()> {
 			};
 		}
 		ctx.send_to("event_loop", PAStatus::PulseAudioDisconnected);
+
+		let retry_time = VARIABLES.read().unwrap().pa_retry_time;
 		for i in 0..retry_time {
 			ctx.send_to("event_loop", PAStatus::RetryIn(retry_time - i));
 
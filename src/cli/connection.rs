@@ -0,0 +1,115 @@
+use tokio::{
+	sync::mpsc,
+	task::{self, JoinHandle},
+};
+
+use super::CliError;
+use crate::{
+	entry::{Entries, EntryIdentifier},
+	models::{EntryUpdate, PulseAudioAction},
+	pa::{self, common::PAInternal},
+	prelude::*,
+};
+
+// drives pa::start directly, the same way PulseActor does, but without
+// the actor system and the UI
+pub struct Connection {
+	internal_sx: cb_channel::Sender<PAInternal>,
+	info_rx: mpsc::UnboundedReceiver<EntryIdentifier>,
+	actions_rx: mpsc::UnboundedReceiver<EntryUpdate>,
+	finished_rx: mpsc::UnboundedReceiver<Result<()>>,
+	task: JoinHandle<()>,
+	pub entries: Entries,
+}
+
+impl Connection {
+	pub async fn connect() -> Result<Self> {
+		let (info_sx, info_rx) = mpsc::unbounded_channel();
+		let (actions_sx, actions_rx) = mpsc::unbounded_channel();
+		let (internal_sx, internal_rx) = cb_channel::unbounded();
+		let (finished_sx, finished_rx) = mpsc::unbounded_channel();
+
+		let task = task::spawn_blocking(move || {
+			let res = pa::start(internal_rx, info_sx, actions_sx);
+			let _ = finished_sx.send(res);
+		});
+
+		let mut connection = Self {
+			internal_sx,
+			info_rx,
+			actions_rx,
+			finished_rx,
+			task,
+			entries: Entries::default(),
+		};
+
+		connection.sync().await?;
+
+		Ok(connection)
+	}
+
+	pub async fn send(&mut self, action: PulseAudioAction) -> Result<()> {
+		self.command(action)?;
+		self.sync().await
+	}
+
+	// waits until pulseaudio has answered every request sent so far
+	pub async fn sync(&mut self) -> Result<()> {
+		self.command(PulseAudioAction::Sync)?;
+
+		loop {
+			tokio::select! {
+				update = self.actions_rx.recv() => {
+					match update {
+						Some(EntryUpdate::Synced) => {
+							return Ok(());
+						}
+						Some(update) => {
+							self.update(update);
+						}
+						None => {
+							return Err(CliError::ConnectionError.into());
+						}
+					}
+				}
+				ident = self.info_rx.recv() => {
+					if let Some(ident) = ident {
+						let _ = self.internal_sx.send(PAInternal::AskInfo(ident));
+					}
+				}
+				res = self.finished_rx.recv() => {
+					return match res {
+						Some(Err(err)) => Err(err).context(CliError::ConnectionError),
+						_ => Err(CliError::ConnectionError.into()),
+					};
+				}
+			};
+		}
+	}
+
+	pub async fn disconnect(self) {
+		let _ = self
+			.internal_sx
+			.send(PAInternal::Command(Box::new(PulseAudioAction::Shutdown)));
+
+		let _ = self.task.await;
+	}
+
+	fn command(&self, action: PulseAudioAction) -> Result<()> {
+		self.internal_sx
+			.send(PAInternal::Command(Box::new(action)))
+			.map_err(|_| CliError::ConnectionError.into())
+	}
+
+	fn update(&mut self, update: EntryUpdate) {
+		match update {
+			EntryUpdate::EntryUpdate(ident, entry) => {
+				self.entries.insert(ident, *entry);
+			}
+			EntryUpdate::EntryRemoved(ident) => {
+				self.entries.remove(&ident);
+			}
+			_ => {}
+		}
+	}
+}
@@ -0,0 +1,30 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum CliError {
+	#[error("cannot connect to pulseaudio")]
+	ConnectionError,
+	#[error("'{0}' does not match any entry")]
+	NoMatch(String),
+	#[error("'{0}' matches more than one entry")]
+	AmbiguousMatch(String),
+	#[error("'{0}' is not a valid volume")]
+	InvalidVolume(String),
+	#[error("'{0}' is not a valid mute mode (expected on, off or toggle)")]
+	InvalidMuteMode(String),
+	#[error("'{0}' is not a valid output format (expected json or tsv)")]
+	InvalidFormat(String),
+	#[error("'{0}' is not a saved scene")]
+	NoScene(String),
+}
+
+impl CliError {
+	pub fn exit_code(&self) -> i32 {
+		match self {
+			Self::ConnectionError => 1,
+			Self::NoMatch(_) | Self::NoScene(_) => 2,
+			Self::AmbiguousMatch(_) => 3,
+			Self::InvalidVolume(_) | Self::InvalidMuteMode(_) | Self::InvalidFormat(_) => 4,
+		}
+	}
+}
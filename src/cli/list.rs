@@ -0,0 +1,250 @@
+use serde::Serialize;
+
+use super::CliError;
+use crate::{
+	cli_options::ListOptions,
+	entry::{Entries, Entry, EntryType, Port},
+	prelude::*,
+	util::channel_volume_to_percent,
+};
+
+#[derive(Serialize, Default)]
+struct List {
+	sinks: Vec<PlayEntryInfo>,
+	sink_inputs: Vec<PlayEntryInfo>,
+	sources: Vec<PlayEntryInfo>,
+	source_outputs: Vec<PlayEntryInfo>,
+	cards: Vec<CardEntryInfo>,
+}
+
+#[derive(Serialize)]
+struct PlayEntryInfo {
+	index: u32,
+	name: String,
+	volume: Vec<u16>,
+	mute: bool,
+	parent: Option<u32>,
+	monitor_source: Option<u32>,
+	suspended: bool,
+	active_port: Option<String>,
+	ports: Vec<PortInfo>,
+}
+
+#[derive(Serialize)]
+struct CardEntryInfo {
+	index: u32,
+	name: String,
+	active_profile: Option<String>,
+	profiles: Vec<CardProfileInfo>,
+	ports: Vec<PortInfo>,
+}
+
+#[derive(Serialize)]
+struct CardProfileInfo {
+	name: String,
+	description: String,
+	n_sinks: u32,
+	n_sources: u32,
+	priority: u32,
+	#[cfg(feature = "pa_v13")]
+	available: bool,
+}
+
+#[derive(Serialize)]
+struct PortInfo {
+	name: String,
+	description: String,
+	available: bool,
+}
+
+impl From<&Port> for PortInfo {
+	fn from(port: &Port) -> Self {
+		Self {
+			name: port.name.clone(),
+			description: port.description.clone(),
+			available: port.available,
+		}
+	}
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Format {
+	Json,
+	Tsv,
+}
+
+// checked before connecting, so a typo is reported even without a server
+pub fn format(opts: &ListOptions) -> Result<Format, CliError> {
+	match opts.format.as_deref().unwrap_or("json") {
+		"json" => Ok(Format::Json),
+		"tsv" => Ok(Format::Tsv),
+		format => Err(CliError::InvalidFormat(format.to_string())),
+	}
+}
+
+pub fn print(entries: &Entries, format: Format) -> Result<()> {
+	print!("{}", render(entries, format)?);
+
+	Ok(())
+}
+
+// the whole output, every line ends with a newline
+pub fn render(entries: &Entries, format: Format) -> Result<String> {
+	let mut output = String::new();
+
+	match format {
+		Format::Json => {
+			output.push_str(&serde_json::to_string(&List::new(entries))?);
+			output.push('\n');
+		}
+		Format::Tsv => {
+			for types in &[
+				EntryType::Sink,
+				EntryType::SinkInput,
+				EntryType::Source,
+				EntryType::SourceOutput,
+				EntryType::Card,
+			] {
+				for (_, entry) in entries.iter_type(*types) {
+					output.push_str(&tsv_line(entry));
+					output.push('\n');
+				}
+			}
+		}
+	};
+
+	Ok(output)
+}
+
+impl List {
+	fn new(entries: &Entries) -> Self {
+		let mut list = Self::default();
+
+		let play_entries = |entry_type| {
+			entries
+				.iter_type(entry_type)
+				.filter_map(|(_, e)| PlayEntryInfo::new(e))
+				.collect()
+		};
+
+		list.sinks = play_entries(EntryType::Sink);
+		list.sink_inputs = play_entries(EntryType::SinkInput);
+		list.sources = play_entries(EntryType::Source);
+		list.source_outputs = play_entries(EntryType::SourceOutput);
+		list.cards = entries
+			.iter_type(EntryType::Card)
+			.filter_map(|(_, e)| CardEntryInfo::new(e))
+			.collect();
+
+		list
+	}
+}
+
+impl PlayEntryInfo {
+	fn new(entry: &Entry) -> Option<Self> {
+		let play = entry.entry_kind.play_entry()?;
+
+		Some(Self {
+			index: entry.index,
+			name: entry.name.clone(),
+			volume: play
+				.volume
+				.get()
+				.iter()
+				.map(|v| channel_volume_to_percent(*v))
+				.collect(),
+			mute: play.mute,
+			parent: play.parent,
+			monitor_source: if entry.entry_type == EntryType::Sink {
+				play.monitor_source
+			} else {
+				None
+			},
+			suspended: play.suspended,
+			active_port: play
+				.active_port
+				.and_then(|i| play.ports.get(i))
+				.map(|p| p.name.clone()),
+			ports: play.ports.iter().map(PortInfo::from).collect(),
+		})
+	}
+}
+
+impl CardEntryInfo {
+	fn new(entry: &Entry) -> Option<Self> {
+		let card = entry.entry_kind.card_entry()?;
+
+		Some(Self {
+			index: entry.index,
+			name: entry.name.clone(),
+			active_profile: card
+				.selected_profile
+				.and_then(|i| card.profiles.get(i))
+				.map(|p| p.name.clone()),
+			profiles: card
+				.profiles
+				.iter()
+				.map(|p| CardProfileInfo {
+					name: p.name.clone(),
+					description: p.description.clone(),
+					n_sinks: p.n_sinks,
+					n_sources: p.n_sources,
+					priority: p.priority,
+					#[cfg(feature = "pa_v13")]
+					available: p.available,
+				})
+				.collect(),
+			ports: card.ports.iter().map(PortInfo::from).collect(),
+		})
+	}
+}
+
+// type, index, name, volume, mute, parent, monitor source, suspended, active profile, profiles
+fn tsv_line(entry: &Entry) -> String {
+	let entry_type = match entry.entry_type {
+		EntryType::Sink => "sink",
+		EntryType::SinkInput => "sink-input",
+		EntryType::Source => "source",
+		EntryType::SourceOutput => "source-output",
+		EntryType::Card => "card",
+		EntryType::Module => "module",
+		EntryType::Client => "client",
+	};
+
+	let optional = |x: Option<u32>| x.map(|x| x.to_string()).unwrap_or_default();
+	let join = |xs: Vec<String>| xs.join(",");
+
+	let columns = if let Some(info) = PlayEntryInfo::new(entry) {
+		vec![
+			join(info.volume.iter().map(|v| v.to_string()).collect()),
+			info.mute.to_string(),
+			optional(info.parent),
+			optional(info.monitor_source),
+			info.suspended.to_string(),
+			String::new(),
+			String::new(),
+		]
+	} else if let Some(info) = CardEntryInfo::new(entry) {
+		vec![
+			String::new(),
+			String::new(),
+			String::new(),
+			String::new(),
+			String::new(),
+			info.active_profile.unwrap_or_default(),
+			join(info.profiles.into_iter().map(|p| p.name).collect()),
+		]
+	} else {
+		Vec::new()
+	};
+
+	let name = entry.name.replace(['\t', '\n'], " ");
+
+	format!(
+		"{}\t{}\t{}\t{}",
+		entry_type,
+		entry.index,
+		name,
+		columns.join("\t")
+	)
+}
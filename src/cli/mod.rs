@@ -0,0 +1,209 @@
+mod connection;
+mod errors;
+pub mod list;
+pub mod target;
+
+pub use connection::Connection;
+pub use errors::CliError;
+
+use pulse::volume::ChannelVolumes;
+
+use crate::{
+	cli_options::{CliCommand, MoveOptions, MuteOptions, SetProfileOptions, SetVolumeOptions},
+	config::{Scene, Scenes},
+	entry::{Entries, EntryIdentifier, EntryType, PlayEntry},
+	models::PulseAudioAction,
+	prelude::*,
+	util::{shift_volume, volume_to_percent},
+	VARIABLES,
+};
+
+static PLAY_TYPES: [EntryType; 4] = [
+	EntryType::Sink,
+	EntryType::SinkInput,
+	EntryType::Source,
+	EntryType::SourceOutput,
+];
+
+// a command, checked as far as it can be without connecting
+enum Task<'a> {
+	List(list::Format),
+	SaveScene(&'a str),
+	ApplyScene(&'a str),
+	Action(ActionCommand<'a>),
+}
+
+// commands done by sending a single action
+enum ActionCommand<'a> {
+	SetVolume(&'a SetVolumeOptions),
+	Mute(&'a MuteOptions),
+	Move(&'a MoveOptions),
+	SetProfile(&'a SetProfileOptions),
+}
+
+impl<'a> Task<'a> {
+	fn new(command: &'a CliCommand) -> Result<Self, CliError> {
+		Ok(match command {
+			CliCommand::List(opts) => Self::List(list::format(opts)?),
+			CliCommand::SaveScene(opts) => Self::SaveScene(&opts.name),
+			CliCommand::ApplyScene(opts) => Self::ApplyScene(&opts.name),
+			CliCommand::SetVolume(opts) => Self::Action(ActionCommand::SetVolume(opts)),
+			CliCommand::Mute(opts) => Self::Action(ActionCommand::Mute(opts)),
+			CliCommand::Move(opts) => Self::Action(ActionCommand::Move(opts)),
+			CliCommand::SetProfile(opts) => Self::Action(ActionCommand::SetProfile(opts)),
+		})
+	}
+}
+
+pub async fn run(command: CliCommand) -> Result<()> {
+	let task = Task::new(&command)?;
+
+	let mut connection = Connection::connect().await?;
+
+	let result = execute(task, &mut connection).await;
+
+	connection.disconnect().await;
+
+	result
+}
+
+async fn execute(task: Task<'_>, connection: &mut Connection) -> Result<()> {
+	match task {
+		Task::List(format) => list::print(&connection.entries, format),
+		Task::SaveScene(name) => {
+			let mut scenes = Scenes::load()?;
+			scenes.insert(name.to_string(), Scene::capture(&connection.entries));
+
+			scenes.store()
+		}
+		Task::ApplyScene(name) => apply_scene(name, connection).await,
+		Task::Action(command) => {
+			let action = command_to_action(&command, &connection.entries)?;
+
+			connection.send(action).await
+		}
+	}
+}
+
+fn command_to_action(
+	command: &ActionCommand,
+	entries: &Entries,
+) -> Result<PulseAudioAction, CliError> {
+	match command {
+		ActionCommand::SetVolume(opts) => {
+			let entry = target::find_entry(entries, &opts.target, &PLAY_TYPES)?;
+			let play = play_entry(entries, entry.entry_ident, &opts.target)?;
+
+			let max_percent = VARIABLES.read().unwrap().max_volume_of(entry.entry_type);
+			let vols = set_volume(play.volume, &opts.volume, max_percent)?;
+
+			Ok(PulseAudioAction::SetVolume(entry.entry_ident, vols))
+		}
+		ActionCommand::Mute(opts) => {
+			let entry = target::find_entry(entries, &opts.target, &PLAY_TYPES)?;
+			let play = play_entry(entries, entry.entry_ident, &opts.target)?;
+
+			let mute = match &opts.mode[..] {
+				"on" => true,
+				"off" => false,
+				"toggle" => !play.mute,
+				_ => {
+					return Err(CliError::InvalidMuteMode(opts.mode.clone()));
+				}
+			};
+
+			Ok(PulseAudioAction::MuteEntry(entry.entry_ident, mute))
+		}
+		ActionCommand::Move(opts) => {
+			let entry = target::find_entry(
+				entries,
+				&opts.target,
+				&[EntryType::SinkInput, EntryType::SourceOutput],
+			)?;
+
+			let parent_type = if entry.entry_type == EntryType::SinkInput {
+				EntryType::Sink
+			} else {
+				EntryType::Source
+			};
+			let parent = target::find_entry(entries, &opts.parent, &[parent_type])?;
+
+			Ok(PulseAudioAction::MoveEntryToParent(
+				entry.entry_ident,
+				parent.entry_ident,
+			))
+		}
+		ActionCommand::SetProfile(opts) => {
+			let entry = target::find_entry(entries, &opts.target, &[EntryType::Card])?;
+			let card = entries
+				.get_card_entry(&entry.entry_ident)
+				.ok_or_else(|| CliError::NoMatch(opts.target.clone()))?;
+
+			let profile = target::find_profile(&card.profiles, &opts.profile)?;
+
+			Ok(PulseAudioAction::ChangeCardProfile(
+				entry.entry_ident,
+				profile.name.clone(),
+			))
+		}
+	}
+}
+
+async fn apply_scene(name: &str, connection: &mut Connection) -> Result<()> {
+	let scenes = Scenes::load()?;
+	let scene = scenes
+		.get(name)
+		.ok_or_else(|| CliError::NoScene(name.to_string()))?;
+
+	let (actions, unmatched) = scene.restore(&connection.entries);
+
+	for action in actions {
+		connection.send(action).await?;
+	}
+
+	for entry in unmatched {
+		eprintln!("rsmixer: {} not found", entry);
+	}
+
+	Ok(())
+}
+
+fn play_entry<'a>(
+	entries: &'a Entries,
+	ident: EntryIdentifier,
+	target: &str,
+) -> Result<&'a PlayEntry, CliError> {
+	entries
+		.get_play_entry(&ident)
+		.ok_or_else(|| CliError::NoMatch(target.to_string()))
+}
+
+// moves every channel by the same amount, so the average gets to the given
+// volume and the balance between channels stays
+pub fn set_volume(
+	mut volume: ChannelVolumes,
+	value: &str,
+	max_percent: u16,
+) -> Result<ChannelVolumes, CliError> {
+	let current = volume_to_percent(volume).min(i16::MAX as u16) as i16;
+	let target = parse_volume(value, current)?;
+
+	shift_volume(&mut volume, target.saturating_sub(current), max_percent);
+
+	Ok(volume)
+}
+
+// absolute (`40%`) or relative (`+5%`, `-5%`) percentage
+pub fn parse_volume(volume: &str, current: i16) -> Result<i16, CliError> {
+	let value = volume.strip_suffix('%').unwrap_or(volume);
+
+	let percent = value
+		.parse::<i16>()
+		.map_err(|_| CliError::InvalidVolume(volume.to_string()))?;
+
+	if value.starts_with('+') || value.starts_with('-') {
+		Ok(current.saturating_add(percent))
+	} else {
+		Ok(percent)
+	}
+}
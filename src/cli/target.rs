@@ -0,0 +1,99 @@
+use super::CliError;
+use crate::entry::{CardProfile, Entries, Entry, EntryIdentifier, EntryType};
+
+// Targets are matched in this order:
+// - `type:index`, e.g. `sink:0` or `sink-input:42`
+// - pulseaudio name of a sink or source, e.g. `alsa_output.pci-0000_00_1f.3.analog-stereo`
+// - exact description, ignoring case
+// - part of the description, ignoring case
+pub fn find_entry<'a>(
+	entries: &'a Entries,
+	target: &str,
+	types: &[EntryType],
+) -> Result<&'a Entry, CliError> {
+	if let Some(ident) = parse_identifier(target) {
+		if types.contains(&ident.entry_type) {
+			return entries
+				.get(&ident)
+				.ok_or_else(|| CliError::NoMatch(target.to_string()));
+		}
+	}
+
+	let candidates = types
+		.iter()
+		.flat_map(|t| entries.iter_type(*t))
+		.map(|(_, e)| e)
+		.collect::<Vec<&Entry>>();
+
+	// unique, unlike descriptions
+	if let Some(entry) = candidates
+		.iter()
+		.find(|e| matches!(e.entry_kind.play_entry(), Some(p) if !p.device_name.is_empty() && p.device_name == target))
+	{
+		return Ok(entry);
+	}
+
+	find_by_name(candidates, target, |e| &e.name)
+}
+
+pub fn find_profile<'a>(
+	profiles: &'a [CardProfile],
+	target: &str,
+) -> Result<&'a CardProfile, CliError> {
+	match find_by_name(profiles.iter().collect(), target, |p| &p.name) {
+		Err(CliError::NoMatch(_)) => {
+			find_by_name(profiles.iter().collect(), target, |p| &p.description)
+		}
+		res => res,
+	}
+}
+
+fn find_by_name<'a, T, F>(candidates: Vec<&'a T>, target: &str, name: F) -> Result<&'a T, CliError>
+where
+	F: Fn(&T) -> &String,
+{
+	let target_lower = target.to_lowercase();
+
+	let exact = candidates
+		.iter()
+		.filter(|c| name(c).to_lowercase() == target_lower)
+		.copied()
+		.collect::<Vec<&T>>();
+
+	if !exact.is_empty() {
+		return only_one(exact, target);
+	}
+
+	let partial = candidates
+		.iter()
+		.filter(|c| name(c).to_lowercase().contains(&target_lower))
+		.copied()
+		.collect::<Vec<&T>>();
+
+	only_one(partial, target)
+}
+
+fn only_one<'a, T>(mut matches: Vec<&'a T>, target: &str) -> Result<&'a T, CliError> {
+	match matches.len() {
+		0 => Err(CliError::NoMatch(target.to_string())),
+		1 => Ok(matches.remove(0)),
+		_ => Err(CliError::AmbiguousMatch(target.to_string())),
+	}
+}
+
+fn parse_identifier(target: &str) -> Option<EntryIdentifier> {
+	let (entry_type, index) = target.split_once(':')?;
+
+	let entry_type = match entry_type {
+		"sink" => EntryType::Sink,
+		"sink-input" => EntryType::SinkInput,
+		"source" => EntryType::Source,
+		"source-output" => EntryType::SourceOutput,
+		"card" => EntryType::Card,
+		_ => {
+			return None;
+		}
+	};
+
+	Some(EntryIdentifier::new(entry_type, index.parse().ok()?))
+}
@@ -12,15 +12,119 @@ pub struct CliOptions {
 
 	#[options(help = "show this text")]
 	help: bool,
+
+	#[options(command)]
+	command: Option<CliCommand>,
+}
+
+#[derive(Debug, Options)]
+pub enum CliCommand {
+	#[options(help = "set volume of a sink, source or application")]
+	SetVolume(SetVolumeOptions),
+
+	#[options(help = "mute, unmute or toggle mute of a sink, source or application")]
+	Mute(MuteOptions),
+
+	#[options(help = "move an application to another sink or source")]
+	Move(MoveOptions),
+
+	#[options(help = "change the profile of a card")]
+	SetProfile(SetProfileOptions),
+
+	#[options(help = "print all sinks, sources, applications and cards")]
+	List(ListOptions),
+
+	#[options(help = "save volumes, mutes, routing and card profiles as a scene")]
+	SaveScene(SceneOptions),
+
+	#[options(help = "restore a saved scene")]
+	ApplyScene(SceneOptions),
+}
+
+#[derive(Debug, Options)]
+pub struct SetVolumeOptions {
+	#[options(help = "show this text")]
+	help: bool,
+
+	#[options(free, required, help = "sink, source or application")]
+	pub target: String,
+
+	#[options(
+		free,
+		required,
+		help = "volume, either absolute (40%) or relative (+5%, -- -5%)"
+	)]
+	pub volume: String,
+}
+
+#[derive(Debug, Options)]
+pub struct MuteOptions {
+	#[options(help = "show this text")]
+	help: bool,
+
+	#[options(free, required, help = "on, off or toggle")]
+	pub mode: String,
+
+	#[options(free, required, help = "sink, source or application")]
+	pub target: String,
+}
+
+#[derive(Debug, Options)]
+pub struct MoveOptions {
+	#[options(help = "show this text")]
+	help: bool,
+
+	#[options(free, required, help = "application to move")]
+	pub target: String,
+
+	#[options(free, required, help = "sink or source to move the application to")]
+	pub parent: String,
+}
+
+#[derive(Debug, Options)]
+pub struct SetProfileOptions {
+	#[options(help = "show this text")]
+	help: bool,
+
+	#[options(free, required, help = "card to change")]
+	pub target: String,
+
+	#[options(free, required, help = "name or description of the profile")]
+	pub profile: String,
+}
+
+#[derive(Debug, Options)]
+pub struct ListOptions {
+	#[options(help = "show this text")]
+	help: bool,
+
+	#[options(help = "output format: json (default) or tsv", meta = "FORMAT")]
+	pub format: Option<String>,
+}
+
+#[derive(Debug, Options)]
+pub struct SceneOptions {
+	#[options(help = "show this text")]
+	help: bool,
+
+	#[options(free, required, help = "name of the scene")]
+	pub name: String,
 }
 
 impl CliOptions {
-	pub fn check() -> Result<()> {
+	pub fn check() -> Result<Option<CliCommand>> {
 		let opts = CliOptions::parse_args_default_or_exit();
 
-		if opts.help {
-			println!("{}", CliOptions::usage());
-			return Ok(());
+		if opts.help_requested() {
+			match &opts.command {
+				Some(command) => println!("{}", command.self_usage()),
+				None => println!(
+					"{}\n\nAvailable commands:\n{}",
+					CliOptions::usage(),
+					CliOptions::command_list().unwrap_or("")
+				),
+			}
+			std::process::exit(0);
 		}
 
 		if let Some(file) = opts.log_file {
@@ -32,6 +136,6 @@ impl CliOptions {
 			simple_logging::log_to_file(file, lvl).unwrap();
 		}
 
-		Ok(())
+		Ok(opts.command)
 	}
 }
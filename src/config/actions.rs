@@ -13,6 +13,8 @@ impl ToString for UserAction {
 			UserAction::ChangePage(PageType::Output) => "show_output".to_string(),
 			UserAction::ChangePage(PageType::Input) => "show_input".to_string(),
 			UserAction::ChangePage(PageType::Cards) => "show_cards".to_string(),
+			UserAction::ChangePage(PageType::Modules) => "show_modules".to_string(),
+			UserAction::ChangePage(PageType::Log) => "show_log".to_string(),
 			UserAction::OpenContextMenu(_) => "context_menu".to_string(),
 			UserAction::ShowHelp => "help".to_string(),
 			UserAction::RequstChangeVolume(num, _) => {
@@ -37,9 +39,38 @@ impl ToString for UserAction {
 			UserAction::Confirm => "confirm".to_string(),
 			UserAction::Hide(_) => "hide".to_string(),
 			UserAction::InputVolumeValue => "input_volume_value".to_string(),
-			UserAction::ChangeVolumeInputValue(_, _) | UserAction::SetSelected(_) => {
-				"unsupported".to_string()
+			UserAction::ShowChannels => "show_channels".to_string(),
+			UserAction::ShowDetails => "show_details".to_string(),
+			UserAction::RequestChangeChannelVolume(num) => {
+				if *num < 0 {
+					format!("lower_channel_volume({})", num)
+				} else {
+					format!("raise_channel_volume({})", num)
+				}
 			}
+			UserAction::RequestChangeBalance(num) => {
+				if *num < 0 {
+					format!("balance_left({})", num)
+				} else {
+					format!("balance_right({})", num)
+				}
+			}
+			UserAction::ToggleChannelLock => "toggle_channel_lock".to_string(),
+			UserAction::OpenFilter => "filter".to_string(),
+			UserAction::LoadModule => "load_module".to_string(),
+			UserAction::CreateNullSink => "create_null_sink".to_string(),
+			UserAction::TogglePicked => "pick".to_string(),
+			UserAction::UnloadCreatedModules => "unload_created_modules".to_string(),
+			UserAction::ToggleMarked => "mark".to_string(),
+			UserAction::Undo => "undo".to_string(),
+			UserAction::Redo => "redo".to_string(),
+			UserAction::ApplyScene(name) => format!("apply_scene({})", name),
+			UserAction::SaveScene(name) => format!("save_scene({})", name),
+			UserAction::ChangeVolumeInputValue(_, _)
+			| UserAction::ChangeFilterValue(_, _)
+			| UserAction::ChangeModuleInputValue(_, _)
+			| UserAction::StartPicking(_)
+			| UserAction::SetSelected(_) => "unsupported".to_string(),
 		}
 	}
 }
@@ -72,6 +103,8 @@ impl TryFrom<String> for UserAction {
 			"show_output" => UserAction::ChangePage(PageType::Output),
 			"show_input" => UserAction::ChangePage(PageType::Input),
 			"show_cards" => UserAction::ChangePage(PageType::Cards),
+			"show_modules" => UserAction::ChangePage(PageType::Modules),
+			"show_log" => UserAction::ChangePage(PageType::Log),
 			"context_menu" => UserAction::OpenContextMenu(None),
 			"help" => UserAction::ShowHelp,
 			"lower_volume" => {
@@ -92,6 +125,42 @@ impl TryFrom<String> for UserAction {
 				};
 				UserAction::RequstChangeVolume(a, None)
 			}
+			"lower_channel_volume" => {
+				let a = match a.parse::<i16>() {
+					Ok(x) => x,
+					Err(_) => {
+						return Err(ConfigError::ActionBindingError(st.clone()));
+					}
+				};
+				UserAction::RequestChangeChannelVolume(-a)
+			}
+			"raise_channel_volume" => {
+				let a = match a.parse::<i16>() {
+					Ok(x) => x,
+					Err(_) => {
+						return Err(ConfigError::ActionBindingError(st.clone()));
+					}
+				};
+				UserAction::RequestChangeChannelVolume(a)
+			}
+			"balance_left" => {
+				let a = match a.parse::<i16>() {
+					Ok(x) => x,
+					Err(_) => {
+						return Err(ConfigError::ActionBindingError(st.clone()));
+					}
+				};
+				UserAction::RequestChangeBalance(-a)
+			}
+			"balance_right" => {
+				let a = match a.parse::<i16>() {
+					Ok(x) => x,
+					Err(_) => {
+						return Err(ConfigError::ActionBindingError(st.clone()));
+					}
+				};
+				UserAction::RequestChangeBalance(a)
+			}
 			"up" => {
 				let a = match a.parse::<u16>() {
 					Ok(x) => x,
@@ -115,6 +184,19 @@ impl TryFrom<String> for UserAction {
 			"cycle_pages_forward" => UserAction::CyclePages(1),
 			"cycle_pages_backward" => UserAction::CyclePages(-1),
 			"input_volume_value" => UserAction::InputVolumeValue,
+			"show_channels" => UserAction::ShowChannels,
+			"show_details" => UserAction::ShowDetails,
+			"toggle_channel_lock" => UserAction::ToggleChannelLock,
+			"filter" => UserAction::OpenFilter,
+			"load_module" => UserAction::LoadModule,
+			"create_null_sink" => UserAction::CreateNullSink,
+			"pick" => UserAction::TogglePicked,
+			"unload_created_modules" => UserAction::UnloadCreatedModules,
+			"mark" => UserAction::ToggleMarked,
+			"undo" => UserAction::Undo,
+			"redo" => UserAction::Redo,
+			"apply_scene" if !a.is_empty() => UserAction::ApplyScene(a),
+			"save_scene" if !a.is_empty() => UserAction::SaveScene(a),
 			"close_context_menu" => UserAction::CloseContextMenu,
 			"confirm" => UserAction::Confirm,
 			"hide" => UserAction::Hide(None),
@@ -3,9 +3,9 @@ use crossterm::style::Color;
 pub fn str_to_color(s: &str) -> Option<Color> {
 	if s.chars().take(1).collect::<String>() == "#" && s.len() == 7 {
 		Some(Color::Rgb {
-			r: u8::from_str_radix(&s[1..3], 16).expect("error in config"),
-			g: u8::from_str_radix(&s[3..5], 16).expect("error in config"),
-			b: u8::from_str_radix(&s[5..7], 16).expect("error in config"),
+			r: u8::from_str_radix(s.get(1..3)?, 16).ok()?,
+			g: u8::from_str_radix(s.get(3..5)?, 16).ok()?,
+			b: u8::from_str_radix(s.get(5..7)?, 16).ok()?,
 		})
 	} else {
 		match &s[..].parse::<Color>() {
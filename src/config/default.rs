@@ -25,16 +25,38 @@ impl std::default::Default for RsMixerConfig {
 		bindings.insert("scroll_down".to_string(), "lower_volume(5)".to_string());
 		bindings.insert("scroll_up".to_string(), "raise_volume(5)".to_string());
 
+		bindings.insert("left".to_string(), "lower_channel_volume(1)".to_string());
+		bindings.insert("right".to_string(), "raise_channel_volume(1)".to_string());
+		bindings.insert("h".to_string(), "lower_channel_volume(5)".to_string());
+		bindings.insert("l".to_string(), "raise_channel_volume(5)".to_string());
+		bindings.insert("scroll_down".to_string(), "lower_channel_volume(5)".to_string());
+		bindings.insert("scroll_up".to_string(), "raise_channel_volume(5)".to_string());
+		bindings.insert("<".to_string(), "balance_left(5)".to_string());
+		bindings.insert(">".to_string(), "balance_right(5)".to_string());
+		bindings.insert("c".to_string(), "show_channels".to_string());
+		bindings.insert("shift+c".to_string(), "toggle_channel_lock".to_string());
+		bindings.insert("i".to_string(), "show_details".to_string());
+
+		bindings.insert("/".to_string(), "filter".to_string());
+		bindings.insert("space".to_string(), "pick".to_string());
+		bindings.insert("space".to_string(), "mark".to_string());
+
 		bindings.insert("m".to_string(), "mute".to_string());
+		bindings.insert("u".to_string(), "undo".to_string());
+		bindings.insert("ctrl+r".to_string(), "redo".to_string());
 		bindings.insert("mouse_middle".to_string(), "mute".to_string());
 		bindings.insert("mouse_right".to_string(), "mute".to_string());
 
 		bindings.insert("1".to_string(), "show_output".to_string());
 		bindings.insert("2".to_string(), "show_input".to_string());
 		bindings.insert("3".to_string(), "show_cards".to_string());
+		bindings.insert("4".to_string(), "show_modules".to_string());
+		bindings.insert("5".to_string(), "show_log".to_string());
 		bindings.insert("F1".to_string(), "show_output".to_string());
 		bindings.insert("F2".to_string(), "show_input".to_string());
 		bindings.insert("F3".to_string(), "show_cards".to_string());
+		bindings.insert("F4".to_string(), "show_modules".to_string());
+		bindings.insert("F5".to_string(), "show_log".to_string());
 		bindings.insert("tab".to_string(), "cycle_pages_forward".to_string());
 		bindings.insert("shift+tab".to_string(), "cycle_pages_backward".to_string());
 
@@ -92,6 +114,14 @@ impl std::default::Default for RsMixerConfig {
 				attributes: None,
 			},
 		);
+		c.insert(
+			"warning".to_string(),
+			ConfigColor {
+				fg: Some("red".to_string()),
+                bg: None,
+				attributes: Some(vec!["bold".to_string()]),
+			},
+		);
 		c.insert(
 			"green".to_string(),
 			ConfigColor {
@@ -104,8 +134,12 @@ impl std::default::Default for RsMixerConfig {
 		Self {
 			version: Some(String::from(VERSION)),
 			pulse_audio: None,
+			meter: None,
+			events: None,
+			fallback: None,
 			bindings,
 			colors: c,
+			rules: None,
 		}
 	}
 }
@@ -10,6 +10,12 @@ pub enum ConfigError {
 	ActionBindingError(String),
 	#[error("'{0}' is not a valid key color")]
 	InvalidColor(String),
+	#[error("'{0}' is not a valid volume display, use percent, db, linear or raw")]
+	InvalidVolumeDisplay(String),
+	#[error("'{0}' is not a sink, sink_input, source or source_output")]
+	InvalidEntryType(String),
 	#[error("'{0}' is not a valid key version code")]
 	InvalidVersion(String),
+	#[error("Cannot find the config directory")]
+	NoConfigDirectory,
 }
@@ -65,6 +65,7 @@ pub fn try_string_to_keyevent(
 		"insert" => KeyCode::Insert,
 		"null" => KeyCode::Null,
 		"esc" => KeyCode::Esc,
+		"space" => KeyCode::Char(' '),
 		_ => match code.len() {
 			1 => {
 				let big_c = code.to_uppercase().chars().next().unwrap();
@@ -3,30 +3,42 @@ mod colors;
 mod default;
 mod errors;
 pub mod keys_mouse;
+mod rules;
+mod scenes;
 mod variables;
 
-use std::{collections::HashMap, convert::TryFrom};
+use std::{collections::HashMap, convert::TryFrom, path::PathBuf, sync::RwLock, time::Duration};
 
 use crossterm::style::{Attribute, ContentStyle};
+use directories::ProjectDirs;
 pub use errors::ConfigError;
 use linked_hash_map::LinkedHashMap;
+pub use rules::Rule;
+pub use scenes::{Scene, Scenes};
 use semver::Version;
 use serde::{Deserialize, Serialize};
 pub use variables::Variables;
 
 use crate::{
-	models::{InputEvent, UserAction},
+	entry::EntryType,
+	models::{Ballistics, InputEvent, UserAction},
 	multimap::MultiMap,
+	notifications,
 	prelude::*,
-	Styles, VERSION,
+	util::VolumeDisplay,
+	Styles, BINDINGS, STYLES, VARIABLES, VERSION,
 };
 
 #[derive(Serialize, Deserialize, Clone)]
 pub struct RsMixerConfig {
 	version: Option<String>,
 	pulse_audio: Option<PulseAudio>,
+	meter: Option<Meter>,
+	events: Option<Events>,
+	fallback: Option<Fallback>,
 	bindings: MultiMap<String, String>,
 	colors: LinkedHashMap<String, ConfigColor>,
+	rules: Option<Vec<Rule>>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Default)]
@@ -35,6 +47,11 @@ pub struct PulseAudio {
 	retry_time: Option<u64>,
 	rate: Option<u32>,
 	frag_size: Option<u32>,
+	monitor_channels: Option<bool>,
+	volume_display: Option<String>,
+	// in percent
+	max_volume: Option<u16>,
+	max_volume_by_type: Option<HashMap<String, u16>>,
 }
 
 impl PulseAudio {
@@ -50,6 +67,106 @@ impl PulseAudio {
 	pub fn frag_size(&self) -> u32 {
 		self.frag_size.unwrap_or(48)
 	}
+	pub fn monitor_channels(&self) -> bool {
+		self.monitor_channels.unwrap_or(false)
+	}
+	pub fn volume_display(&self) -> Result<VolumeDisplay> {
+		match &self.volume_display {
+			Some(name) => VolumeDisplay::from_name(name)
+				.ok_or_else(|| ConfigError::InvalidVolumeDisplay(name.clone()))
+				.context("while parsing config file"),
+			None => Ok(VolumeDisplay::Percent),
+		}
+	}
+	pub fn max_volume(&self) -> u16 {
+		self.max_volume.unwrap_or(150)
+	}
+	pub fn max_volume_by_type(&self) -> Result<HashMap<EntryType, u16>> {
+		let mut max_volumes = HashMap::new();
+
+		for (name, max) in self.max_volume_by_type.iter().flatten() {
+			let entry_type = match &name[..] {
+				"sink" => EntryType::Sink,
+				"sink_input" => EntryType::SinkInput,
+				"source" => EntryType::Source,
+				"source_output" => EntryType::SourceOutput,
+				_ => {
+					return Err(ConfigError::InvalidEntryType(name.clone()))
+						.context("while parsing config file");
+				}
+			};
+			max_volumes.insert(entry_type, *max);
+		}
+
+		Ok(max_volumes)
+	}
+}
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct Meter {
+	attack: Option<u64>,
+	decay: Option<f32>,
+	peak_hold: Option<u64>,
+	floor: Option<f32>,
+}
+
+impl Meter {
+	pub fn ballistics(&self) -> Ballistics {
+		let def = Ballistics::default();
+
+		Ballistics {
+			attack: self.attack.map(Duration::from_millis).unwrap_or(def.attack),
+			decay: self.decay.map(f32::abs).unwrap_or(def.decay),
+			peak_hold: self
+				.peak_hold
+				.map(Duration::from_millis)
+				.unwrap_or(def.peak_hold),
+			// a floor above 0 dBFS would leave nothing to show
+			floor: self.floor.map(|f| -f.abs()).unwrap_or(def.floor),
+		}
+	}
+}
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct Events {
+	toasts: Option<bool>,
+	// in milliseconds
+	toast_duration: Option<u64>,
+	notifications: Option<bool>,
+	// events kept on the log page
+	log_size: Option<usize>,
+}
+
+impl Events {
+	pub fn toasts(&self) -> bool {
+		self.toasts.unwrap_or(true)
+	}
+	pub fn toast_duration(&self) -> Duration {
+		Duration::from_millis(self.toast_duration.unwrap_or(4000))
+	}
+	pub fn notifications(&self) -> bool {
+		self.notifications.unwrap_or(false)
+	}
+	pub fn log_size(&self) -> usize {
+		self.log_size.unwrap_or(500)
+	}
+}
+
+// sinks and sources streams fall back to, most preferred first,
+// as their descriptions or names
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct Fallback {
+	sinks: Option<Vec<String>>,
+	sources: Option<Vec<String>>,
+}
+
+impl Fallback {
+	pub fn sinks(&self) -> Vec<String> {
+		self.sinks.clone().unwrap_or_default()
+	}
+	pub fn sources(&self) -> Vec<String> {
+		self.sources.clone().unwrap_or_default()
+	}
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -65,11 +182,57 @@ impl RsMixerConfig {
 		Ok(config)
 	}
 
+	// loads the config file and replaces current styles, bindings and variables
+	pub fn reload() -> Result<()> {
+		Self::load()?.replace(&STYLES, &BINDINGS, &VARIABLES)
+	}
+
+	// nothing is replaced when any part of the config is invalid
+	pub fn replace(
+		mut self,
+		styles: &RwLock<Styles>,
+		bindings: &RwLock<MultiMap<InputEvent, UserAction>>,
+		variables: &RwLock<Variables>,
+	) -> Result<()> {
+		let (new_styles, new_bindings, new_variables) = self.interpret()?;
+
+		*styles.write().unwrap() = new_styles;
+		*bindings.write().unwrap() = new_bindings;
+		*variables.write().unwrap() = new_variables;
+
+		Ok(())
+	}
+
+	// the same file confy uses
+	pub fn path() -> Option<PathBuf> {
+		let project = ProjectDirs::from("rs", "", "rsmixer")?;
+
+		Some(project.config_dir().join("rsmixer.toml"))
+	}
+
 	pub fn interpret(&mut self) -> Result<(Styles, MultiMap<InputEvent, UserAction>, Variables)> {
 		self.compatibility_layer()?;
 
 		let bindings = self.bindings()?;
 
+		if let Some(pulse) = &self.pulse_audio {
+			pulse.volume_display()?;
+			pulse.max_volume_by_type()?;
+		}
+
+		if let Some(events) = &self.events {
+			if events.notifications() && !notifications::available() {
+				log::warn!("notifications are enabled, but rsmixer was built without them");
+			}
+		}
+
+		// added after the config file of older versions was written
+		if !self.colors.contains_key("warning") {
+			if let Some(c) = Self::default().colors.get("warning") {
+				self.colors.insert("warning".to_string(), c.clone());
+			}
+		}
+
 		let mut styles: Styles = HashMap::new();
 
 		for (k, v) in &self.colors {
@@ -113,9 +276,12 @@ impl RsMixerConfig {
 			styles.insert(k.into(), c);
 		}
 
-		self.version = Some(String::from(VERSION));
+		// only write the file when it needs upgrading, it might be open in an editor
+		if self.version.as_deref() != Some(VERSION) {
+			self.version = Some(String::from(VERSION));
 
-		confy::store("rsmixer", self.clone())?;
+			confy::store("rsmixer", self.clone())?;
+		}
 
 		Ok((styles, bindings, Variables::new(self)))
 	}
@@ -0,0 +1,112 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	entry::{Entries, Entry, EntryType},
+	models::PulseAudioAction,
+	util::percent_to_volume,
+};
+
+// applied to sink inputs and source outputs when they show up,
+// the first rule that matches wins
+#[derive(Serialize, Deserialize, Clone, Default, Debug)]
+pub struct Rule {
+	application: Option<String>,
+	properties: Option<BTreeMap<String, String>>,
+	pub volume: Option<u16>,
+	pub mute: Option<bool>,
+	pub target: Option<String>,
+	pub hidden: Option<bool>,
+}
+
+impl Rule {
+	pub fn matches(&self, name: &str, properties: &BTreeMap<String, String>) -> bool {
+		if self.application.is_none() && self.properties.is_none() {
+			return false;
+		}
+
+		if let Some(application) = &self.application {
+			if !application.eq_ignore_ascii_case(name) {
+				return false;
+			}
+		}
+
+		if let Some(props) = &self.properties {
+			if props.iter().any(|(k, v)| properties.get(k) != Some(v)) {
+				return false;
+			}
+		}
+
+		true
+	}
+
+	// what a matching rule changes on a stream that just showed up
+	pub fn actions(
+		&self,
+		entry: &Entry,
+		entries: &Entries,
+		max_percent: u16,
+	) -> Vec<PulseAudioAction> {
+		let mut actions = Vec::new();
+
+		let (play, parent_type) =
+			match (entry.entry_kind.play_entry(), parent_type(entry.entry_type)) {
+				(Some(play), Some(parent_type)) => (play, parent_type),
+				_ => return actions,
+			};
+		let ident = entry.entry_ident;
+
+		if let Some(volume) = self.volume {
+			let mut vols = play.volume;
+			for v in vols.get_mut() {
+				// clamped first, a huge volume would wrap around as i16
+				v.0 = percent_to_volume(volume.min(max_percent) as i16, max_percent);
+			}
+
+			actions.push(PulseAudioAction::SetVolume(ident, vols));
+		}
+
+		if let Some(mute) = self.mute {
+			if mute != play.mute {
+				actions.push(PulseAudioAction::MuteEntry(ident, mute));
+			}
+		}
+
+		if let Some(target) = &self.target {
+			// the name tells apart devices with the same description, and stays when they're renamed
+			let by_name = entries.iter_type(parent_type).find(
+				|(_, e)| matches!(e.entry_kind.play_entry(), Some(p) if p.device_name == *target),
+			);
+			let by_description = || {
+				entries
+					.iter_type(parent_type)
+					.find(|(_, e)| e.name.eq_ignore_ascii_case(target))
+			};
+
+			match by_name.or_else(by_description) {
+				Some((parent, _)) if Some(parent.index) != play.parent => {
+					actions.push(PulseAudioAction::MoveEntryToParent(ident, *parent));
+				}
+				Some(_) => {}
+				None => {
+					log::warn!(
+						"rule target {} does not match any {:?}",
+						target,
+						parent_type
+					);
+				}
+			}
+		}
+
+		actions
+	}
+}
+
+fn parent_type(entry_type: EntryType) -> Option<EntryType> {
+	match entry_type {
+		EntryType::SinkInput => Some(EntryType::Sink),
+		EntryType::SourceOutput => Some(EntryType::Source),
+		_ => None,
+	}
+}
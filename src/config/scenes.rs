@@ -0,0 +1,305 @@
+use std::{
+	collections::{BTreeMap, HashSet},
+	path::PathBuf,
+};
+
+use serde::{Deserialize, Serialize};
+
+use super::{ConfigError, RsMixerConfig};
+use crate::{
+	entry::{Entries, Entry, EntryIdentifier, EntryType},
+	models::PulseAudioAction,
+	prelude::*,
+	unwrap_or_return,
+	util::{channel_volume_to_percent, percent_to_volume},
+	VARIABLES,
+};
+
+// named snapshots of every sink, source, application and card,
+// kept in scenes.toml next to the config file
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(transparent)]
+pub struct Scenes(BTreeMap<String, Scene>);
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug)]
+pub struct Scene {
+	#[serde(default)]
+	sinks: Vec<ScenePlayEntry>,
+	#[serde(default)]
+	sink_inputs: Vec<ScenePlayEntry>,
+	#[serde(default)]
+	sources: Vec<ScenePlayEntry>,
+	#[serde(default)]
+	source_outputs: Vec<ScenePlayEntry>,
+	#[serde(default)]
+	cards: Vec<SceneCard>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+struct ScenePlayEntry {
+	name: String,
+	volume: Vec<u16>,
+	mute: bool,
+	// name of the sink or source an application plays to or records from
+	parent: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+struct SceneCard {
+	name: String,
+	profile: Option<String>,
+}
+
+impl Scenes {
+	pub fn load() -> Result<Self> {
+		let path = Self::path()?;
+		// confy would create the file
+		if !path.exists() {
+			return Ok(Self::default());
+		}
+
+		let scenes: Scenes = confy::load_path(path).map_err(ConfigError::from)?;
+		Ok(scenes)
+	}
+
+	pub fn store(&self) -> Result<()> {
+		confy::store_path(Self::path()?, self.clone()).map_err(ConfigError::from)?;
+		Ok(())
+	}
+
+	pub fn path() -> Result<PathBuf> {
+		RsMixerConfig::path()
+			.map(|p| p.with_file_name("scenes.toml"))
+			.ok_or_else(|| ConfigError::NoConfigDirectory.into())
+	}
+
+	pub fn get(&self, name: &str) -> Option<&Scene> {
+		self.0.get(name)
+	}
+
+	pub fn insert(&mut self, name: String, scene: Scene) {
+		self.0.insert(name, scene);
+	}
+
+	pub fn names(&self) -> impl Iterator<Item = &String> {
+		self.0.keys()
+	}
+}
+
+impl Scene {
+	pub fn capture(entries: &Entries) -> Self {
+		let play_entries = |entry_type, parent_type| {
+			entries
+				.iter_type(entry_type)
+				.filter_map(|(_, e)| ScenePlayEntry::new(entries, e, parent_type))
+				.collect()
+		};
+
+		Self {
+			sinks: play_entries(EntryType::Sink, None),
+			sink_inputs: play_entries(EntryType::SinkInput, Some(EntryType::Sink)),
+			sources: play_entries(EntryType::Source, None),
+			source_outputs: play_entries(EntryType::SourceOutput, Some(EntryType::Source)),
+			cards: entries
+				.iter_type(EntryType::Card)
+				.filter_map(|(_, e)| SceneCard::new(e))
+				.collect(),
+		}
+	}
+
+	// returns actions needed to bring entries to the saved state
+	// and descriptions of saved entries that don't exist anymore
+	pub fn restore(&self, entries: &Entries) -> (Vec<PulseAudioAction>, Vec<String>) {
+		let mut actions = Vec::new();
+		let mut unmatched = Vec::new();
+		// entries are matched by name, each one can be matched only once
+		let mut used = HashSet::new();
+
+		for (saved_entries, entry_type, parent_type) in &[
+			(&self.sinks, EntryType::Sink, None),
+			(
+				&self.sink_inputs,
+				EntryType::SinkInput,
+				Some(EntryType::Sink),
+			),
+			(&self.sources, EntryType::Source, None),
+			(
+				&self.source_outputs,
+				EntryType::SourceOutput,
+				Some(EntryType::Source),
+			),
+		] {
+			for saved in saved_entries.iter() {
+				let entry = match find(entries, *entry_type, &saved.name, &used) {
+					Some(e) => e,
+					None => {
+						unmatched.push(format!("{} {}", type_name(*entry_type), saved.name));
+						continue;
+					}
+				};
+				used.insert(entry.entry_ident);
+
+				saved.restore(entries, entry, *parent_type, &mut actions, &mut unmatched);
+			}
+		}
+
+		for saved in &self.cards {
+			match find(entries, EntryType::Card, &saved.name, &used) {
+				Some(entry) => {
+					used.insert(entry.entry_ident);
+
+					saved.restore(entry, &mut actions, &mut unmatched);
+				}
+				None => {
+					unmatched.push(format!("{} {}", type_name(EntryType::Card), saved.name));
+				}
+			}
+		}
+
+		(actions, unmatched)
+	}
+}
+
+impl ScenePlayEntry {
+	fn new(entries: &Entries, entry: &Entry, parent_type: Option<EntryType>) -> Option<Self> {
+		let play = entry.entry_kind.play_entry()?;
+
+		let parent = parent_type.and_then(|t| {
+			let ident = EntryIdentifier::new(t, play.parent?);
+			entries.get(&ident).map(|p| p.name.clone())
+		});
+
+		Some(Self {
+			name: entry.name.clone(),
+			volume: play
+				.volume
+				.get()
+				.iter()
+				.map(|v| channel_volume_to_percent(*v))
+				.collect(),
+			mute: play.mute,
+			parent,
+		})
+	}
+
+	fn restore(
+		&self,
+		entries: &Entries,
+		entry: &Entry,
+		parent_type: Option<EntryType>,
+		actions: &mut Vec<PulseAudioAction>,
+		unmatched: &mut Vec<String>,
+	) {
+		let play = unwrap_or_return!(entry.entry_kind.play_entry());
+		let ident = entry.entry_ident;
+
+		let current = play
+			.volume
+			.get()
+			.iter()
+			.map(|v| channel_volume_to_percent(*v))
+			.collect::<Vec<u16>>();
+
+		if !self.volume.is_empty() && current != self.volume {
+			let mut vols = play.volume;
+
+			let max_percent = VARIABLES.read().unwrap().max_volume_of(entry.entry_type);
+
+			// channel count changed, use the average of saved channels
+			let average =
+				self.volume.iter().map(|v| *v as u32).sum::<u32>() / self.volume.len() as u32;
+
+			for (i, v) in vols.get_mut().iter_mut().enumerate() {
+				let percent = if current.len() == self.volume.len() {
+					self.volume[i] as u32
+				} else {
+					average
+				};
+				// the file can be edited by hand, a huge volume would wrap around as i16
+				v.0 = percent_to_volume(percent.min(max_percent as u32) as i16, max_percent);
+			}
+
+			actions.push(PulseAudioAction::SetVolume(ident, vols));
+		}
+
+		if self.mute != play.mute {
+			actions.push(PulseAudioAction::MuteEntry(ident, self.mute));
+		}
+
+		if let (Some(parent_type), Some(parent)) = (parent_type, &self.parent) {
+			match entries
+				.iter_type(parent_type)
+				.find(|(_, e)| e.name == *parent)
+			{
+				Some((parent_ident, _)) if Some(parent_ident.index) != play.parent => {
+					actions.push(PulseAudioAction::MoveEntryToParent(ident, *parent_ident));
+				}
+				Some(_) => {}
+				None => {
+					unmatched.push(format!("{} {}", type_name(parent_type), parent));
+				}
+			}
+		}
+	}
+}
+
+impl SceneCard {
+	fn new(entry: &Entry) -> Option<Self> {
+		let card = entry.entry_kind.card_entry()?;
+
+		Some(Self {
+			name: entry.name.clone(),
+			profile: card
+				.selected_profile
+				.and_then(|i| card.profiles.get(i))
+				.map(|p| p.name.clone()),
+		})
+	}
+
+	fn restore(
+		&self,
+		entry: &Entry,
+		actions: &mut Vec<PulseAudioAction>,
+		unmatched: &mut Vec<String>,
+	) {
+		let card = unwrap_or_return!(entry.entry_kind.card_entry());
+		let profile = unwrap_or_return!(&self.profile);
+
+		match card.profiles.iter().position(|p| p.name == *profile) {
+			Some(i) if Some(i) != card.selected_profile => {
+				actions.push(PulseAudioAction::ChangeCardProfile(
+					entry.entry_ident,
+					profile.clone(),
+				));
+			}
+			Some(_) => {}
+			None => {
+				unmatched.push(format!("profile {} of card {}", profile, self.name));
+			}
+		}
+	}
+}
+
+fn find<'a>(
+	entries: &'a Entries,
+	entry_type: EntryType,
+	name: &str,
+	used: &HashSet<EntryIdentifier>,
+) -> Option<&'a Entry> {
+	entries
+		.iter_type(entry_type)
+		.find(|(i, e)| e.name == name && !used.contains(i))
+		.map(|(_, e)| e)
+}
+
+fn type_name(entry_type: EntryType) -> &'static str {
+	match entry_type {
+		EntryType::Sink => "sink",
+		EntryType::SinkInput => "sink input",
+		EntryType::Source => "source",
+		EntryType::SourceOutput => "source output",
+		EntryType::Card => "card",
+		EntryType::Module => "module",
+		EntryType::Client => "client",
+	}
+}
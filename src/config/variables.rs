@@ -1,25 +1,88 @@
-use super::{PulseAudio, RsMixerConfig};
+use std::{collections::HashMap, time::Duration};
+
+use super::{Events, Fallback, Meter, PulseAudio, RsMixerConfig, Rule};
+use crate::{entry::EntryType, models::Ballistics, util::VolumeDisplay};
 
 pub struct Variables {
 	pub pa_retry_time: u64,
 	pub pa_disable_live_volume: bool,
 	pub pa_rate: u32,
 	pub pa_frag_size: u32,
+	// monitors record every channel instead of a mono downmix
+	pub pa_monitor_channels: bool,
+	pub volume_display: VolumeDisplay,
+	// in percent, for entry types without their own maximum
+	pub max_volume: u16,
+	pub max_volume_by_type: HashMap<EntryType, u16>,
+	pub meter: Ballistics,
+	// server events shown over the bottom border and sent as desktop notifications
+	pub toasts: bool,
+	pub toast_duration: Duration,
+	pub notifications: bool,
+	pub log_size: usize,
+	// most preferred first
+	pub fallback_sinks: Vec<String>,
+	pub fallback_sources: Vec<String>,
+	pub rules: Vec<Rule>,
+}
+
+impl Default for Variables {
+	fn default() -> Self {
+		Self::new(&RsMixerConfig::default())
+	}
 }
 
 impl Variables {
+	// maximum volume in percent
+	pub fn max_volume_of(&self, entry_type: EntryType) -> u16 {
+		*self
+			.max_volume_by_type
+			.get(&entry_type)
+			.unwrap_or(&self.max_volume)
+	}
+
+	// the fallback list of sinks or sources, empty for other types
+	pub fn fallback_of(&self, entry_type: EntryType) -> &[String] {
+		match entry_type {
+			EntryType::Sink => &self.fallback_sinks,
+			EntryType::Source => &self.fallback_sources,
+			_ => &[],
+		}
+	}
+
 	pub fn new(config: &RsMixerConfig) -> Self {
 		let def = PulseAudio::default();
 		let pulse = match &config.pulse_audio {
 			Some(p) => p,
 			None => &def,
 		};
+		let def_events = Events::default();
+		let events = config.events.as_ref().unwrap_or(&def_events);
+		let def_fallback = Fallback::default();
+		let fallback = config.fallback.as_ref().unwrap_or(&def_fallback);
 
 		Self {
 			pa_retry_time: pulse.retry_time(),
 			pa_rate: pulse.rate(),
 			pa_frag_size: pulse.frag_size(),
+			pa_monitor_channels: pulse.monitor_channels(),
+			// checked while interpreting the config
+			volume_display: pulse.volume_display().unwrap_or(VolumeDisplay::Percent),
+			max_volume: pulse.max_volume(),
+			max_volume_by_type: pulse.max_volume_by_type().unwrap_or_default(),
 			pa_disable_live_volume: pulse.disable_live_volume(),
+			meter: config
+				.meter
+				.as_ref()
+				.map(Meter::ballistics)
+				.unwrap_or_default(),
+			toasts: events.toasts(),
+			toast_duration: events.toast_duration(),
+			notifications: events.notifications(),
+			log_size: events.log_size(),
+			fallback_sinks: fallback.sinks(),
+			fallback_sources: fallback.sources(),
+			rules: config.rules.clone().unwrap_or_default(),
 		}
 	}
 }
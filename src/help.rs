@@ -74,7 +74,7 @@ pub fn generate() -> Vec<HelpLine> {
 
 	let mut volume_deltas = HashSet::new();
 
-	for (_, v) in (*BINDINGS).get().iter() {
+	for (_, v) in BINDINGS.read().unwrap().iter() {
 		if let UserAction::RequstChangeVolume(x, _) = v {
 			volume_deltas.insert(x.abs());
 		}
@@ -101,6 +101,36 @@ pub fn generate() -> Vec<HelpLine> {
 		"Mute/unmute".to_string(),
 		vec![ActionMatcher::Concrete(UserAction::RequestMute(None))],
 	));
+	categories.push((
+		"Channel volume".to_string(),
+		vec![
+			ActionMatcher::Any(UserAction::ShowChannels),
+			ActionMatcher::Any(UserAction::ToggleChannelLock),
+		],
+	));
+	categories.push((
+		"Details".to_string(),
+		vec![ActionMatcher::Any(UserAction::ShowDetails)],
+	));
+	categories.push((
+		"Balance".to_string(),
+		vec![ActionMatcher::Any(UserAction::RequestChangeBalance(0))],
+	));
+	categories.push((
+		"Filter".to_string(),
+		vec![ActionMatcher::Any(UserAction::OpenFilter)],
+	));
+	categories.push((
+		"Mark entries".to_string(),
+		vec![ActionMatcher::Any(UserAction::ToggleMarked)],
+	));
+	categories.push((
+		"Undo/redo".to_string(),
+		vec![
+			ActionMatcher::Any(UserAction::Undo),
+			ActionMatcher::Any(UserAction::Redo),
+		],
+	));
 	categories.push((
 		"Change page".to_string(),
 		vec![ActionMatcher::Any(UserAction::ChangePage(PageType::Output))],
@@ -125,7 +155,7 @@ pub fn generate() -> Vec<HelpLine> {
 			key_events: Vec::new(),
 			category: category.0,
 		};
-		for (k, v) in (*BINDINGS).get().iter() {
+		for (k, v) in BINDINGS.read().unwrap().iter() {
 			for matcher in &category.1 {
 				if matcher.is_matching(v) {
 					hl.key_events.push(k.to_string());
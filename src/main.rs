@@ -8,58 +8,61 @@ static LOGGING_MODULE: &str = "Main";
 mod action_handlers;
 mod actor_system;
 mod actors;
+mod cli;
 mod cli_options;
 mod config;
 mod help;
 mod models;
 mod multimap;
+mod notifications;
 mod pa;
 mod prelude;
+#[cfg(test)]
+mod tests;
 mod ui;
 mod util;
 
-use std::collections::HashMap;
+use std::{collections::HashMap, sync::RwLock};
 
 use actors::*;
-use cli_options::CliOptions;
+use cli::CliError;
+use cli_options::{CliCommand, CliOptions};
 use config::{RsMixerConfig, Variables};
 use crossterm::style::ContentStyle;
 use lazy_static::lazy_static;
 use models::{entry, InputEvent, Style, UserAction};
 use multimap::MultiMap;
 use prelude::*;
-use state::Storage;
 use tokio::runtime;
 
 lazy_static! {
-	pub static ref STYLES: Storage<Styles> = Storage::new();
-	pub static ref VARIABLES: Storage<Variables> = Storage::new();
-	pub static ref BINDINGS: Storage<MultiMap<InputEvent, UserAction>> = Storage::new();
+	pub static ref STYLES: RwLock<Styles> = RwLock::new(HashMap::new());
+	pub static ref VARIABLES: RwLock<Variables> = RwLock::new(Variables::default());
+	pub static ref BINDINGS: RwLock<MultiMap<InputEvent, UserAction>> =
+		RwLock::new(MultiMap::new());
 }
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
 pub type Styles = HashMap<Style, ContentStyle>;
 
-fn load_config_and_options() -> Result<()> {
+fn load_config_and_options() -> Result<Option<CliCommand>> {
 	info!("Checking command line options and config");
 
-	CliOptions::check()?;
+	let command = CliOptions::check()?;
 	debug!("CLI options checked");
 
-	let mut config = RsMixerConfig::load()?;
-	let (styles, bindings, variables) = config.interpret()?;
-
-	STYLES.set(styles);
-	BINDINGS.set(bindings);
-	VARIABLES.set(variables);
+	RsMixerConfig::reload()?;
 	debug!("Config loaded");
 
-	Ok(())
+	Ok(command)
 }
 
 async fn run() -> Result<()> {
-	load_config_and_options()?;
+	if let Some(command) = load_config_and_options()? {
+		debug!("Running command without the UI");
+		return cli::run(command).await;
+	}
 
 	debug!("Starting actor system");
 	let (mut context, worker) = actor_system::new();
@@ -69,6 +72,7 @@ async fn run() -> Result<()> {
 	EventLoopActor::item().register_and_start(&mut context);
 	PulseActor::item().register_and_start(&mut context);
 	InputActor::item().register_and_start(&mut context);
+	ConfigWatcherActor::item().register_and_start(&mut context);
 
 	debug!("Actor system started");
 	actor_system_handle.await?
@@ -82,7 +86,13 @@ fn main() -> Result<()> {
 		debug!("Tokio runtime started");
 
 		if let Err(e) = run().await {
-			println!("{:#?}", e);
+			match e.downcast_ref::<CliError>() {
+				Some(err) => {
+					eprintln!("rsmixer: {:#}", e);
+					std::process::exit(err.exit_code());
+				}
+				None => println!("{:#?}", e),
+			}
 		}
 	});
 
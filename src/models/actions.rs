@@ -1,11 +1,11 @@
 use std::collections::HashMap;
 
 use crossterm::event::Event;
-use pulse::volume::ChannelVolumes;
+use pulse::{channelmap::Map, volume::ChannelVolumes};
 
 use crate::{
 	entry::{Entry, EntryIdentifier},
-	models::PageType,
+	models::{Levels, PageType, VirtualDevice},
 };
 
 #[derive(Clone, PartialEq, Debug)]
@@ -16,6 +16,14 @@ pub enum PAStatus {
 	PulseAudioDisconnected,
 }
 
+#[derive(Clone, PartialEq, Debug)]
+pub enum ConfigStatus {
+	// config file changed and was loaded successfully
+	Reloaded,
+	// config file changed but couldn't be loaded
+	Error(String),
+}
+
 // redraw the whole screen (called every window resize)
 #[derive(Clone, PartialEq, Debug)]
 pub struct ResizeScreen {}
@@ -30,9 +38,23 @@ pub enum EntryUpdate {
 	// entry updates
 	EntryRemoved(EntryIdentifier),
 	EntryUpdate(EntryIdentifier, Box<Entry>),
-	PeakVolumeUpdate(EntryIdentifier, f32),
+	// sent before the first EntryUpdate of an entry created after connecting
+	EntryCreated(EntryIdentifier),
+	// sent before the EntryUpdate following a change on the server
+	EntryChanged(EntryIdentifier),
+	// levels of every channel the monitor records
+	PeakVolumeUpdate(EntryIdentifier, Vec<Levels>),
+	DefaultChanged(EntryIdentifier),
+	// module loaded on request of rsmixer
+	ModuleLoaded(EntryIdentifier),
+	// every request sent before PulseAudioAction::Sync has been answered
+	Synced,
 }
 
+// the toast of an event has been shown long enough, the argument identifies the event
+#[derive(Clone, PartialEq, Debug)]
+pub struct ToastExpired(pub u64);
+
 #[derive(Clone, PartialEq, Debug)]
 pub struct UserInput {
 	pub event: Event,
@@ -65,6 +87,37 @@ pub enum UserAction {
 	InputVolumeValue,
 	ChangeVolumeInputValue(String, u8),
 
+	// per channel volume of the currently selected entry
+	ShowChannels,
+	RequestChangeChannelVolume(i16),
+	// negative - towards left, positive - towards right
+	RequestChangeBalance(i16),
+	ToggleChannelLock,
+
+	// proplist, sample spec, latency and such of the currently selected entry
+	ShowDetails,
+
+	// filter entries by name
+	OpenFilter,
+	ChangeFilterValue(String, u8),
+
+	// modules page, the input is a module name followed by its arguments
+	LoadModule,
+	ChangeModuleInputValue(String, u8),
+
+	// virtual devices on the output page, made of the picked entries
+	CreateNullSink,
+	StartPicking(VirtualDevice),
+	TogglePicked,
+	UnloadCreatedModules,
+
+	// mark entries, so that mute, volume changes, move and kill apply to all of them
+	ToggleMarked,
+
+	// scenes saved in scenes.toml, the argument is the scene name
+	ApplyScene(String),
+	SaveScene(String),
+
 	// context menus
 	OpenContextMenu(Option<EntryIdentifier>),
 	CloseContextMenu,
@@ -74,18 +127,38 @@ pub enum UserAction {
 
 	Hide(Option<EntryIdentifier>),
 
+	// changes sent to pulseaudio
+	Undo,
+	Redo,
+
 	RequestQuit,
 }
 
+// what a peak volume monitor records, the channel map
+// is only used when monitors record every channel
+#[derive(Clone, PartialEq, Debug)]
+pub struct MonitorTarget {
+	pub source: Option<u32>,
+	pub channel_map: Map,
+}
+
 #[derive(Clone, PartialEq, Debug)]
 pub enum PulseAudioAction {
 	RequestPulseAudioState,
 	MuteEntry(EntryIdentifier, bool),
 	MoveEntryToParent(EntryIdentifier, EntryIdentifier),
 	ChangeCardProfile(EntryIdentifier, String),
+	// port name, of a sink or a source
+	SetPort(EntryIdentifier, String),
 	SetVolume(EntryIdentifier, ChannelVolumes),
-	CreateMonitors(HashMap<EntryIdentifier, Option<u32>>),
+	CreateMonitors(HashMap<EntryIdentifier, MonitorTarget>),
 	SetSuspend(EntryIdentifier, bool),
 	KillEntry(EntryIdentifier),
+	// name of the sink or source
+	SetDefault(EntryIdentifier, String),
+	// module name and arguments
+	LoadModule(String, String),
+	UnloadModule(EntryIdentifier),
+	Sync,
 	Shutdown,
 }
@@ -1,7 +1,8 @@
 use crate::{
 	actor_system::Ctx,
-	entry::{Entry, EntryIdentifier, EntryKind, EntryType},
-	models::{PulseAudioAction, UserAction},
+	config::Scenes,
+	entry::{Entry, EntryIdentifier, EntryType},
+	models::{PulseAudioAction, UserAction, VirtualDevice},
 	scrollable,
 	ui::{widgets::ToolWindowWidget, Rect, Scrollable},
 };
@@ -9,26 +10,60 @@ use crate::{
 #[derive(PartialEq, Clone)]
 pub enum ContextMenuOption {
 	MoveToEntry(EntryIdentifier, String),
-	ChangeCardProfile(String, String),
+	// profile name, label and availability
+	ChangeCardProfile(String, String, bool),
 	Kill,
 	Move,
 	Suspend,
 	Resume,
-	SetAsDefault,
+	// name of the sink or source
+	SetAsDefault(String),
 	InputExactVolume,
+	ShowDetails,
+	ApplyScene(String),
+	UnloadModule,
+	LoadModule,
+	CreateNullSink,
+	CreateLoopback,
+	CombineSinks,
+	UnloadCreatedModules,
+	ChangePort,
+	// port name, description and availability
+	SetPort(String, String, bool),
 }
 
 impl From<ContextMenuOption> for String {
 	fn from(option: ContextMenuOption) -> Self {
 		match option {
 			ContextMenuOption::MoveToEntry(_, s) => s,
-			ContextMenuOption::ChangeCardProfile(_, s) => s,
+			ContextMenuOption::ChangeCardProfile(_, s, _) => s,
 			ContextMenuOption::Kill => "Kill".into(),
 			ContextMenuOption::Move => "Move".into(),
 			ContextMenuOption::Suspend => "Suspend".into(),
 			ContextMenuOption::Resume => "Resume".into(),
-			ContextMenuOption::SetAsDefault => "Set as default".into(),
+			ContextMenuOption::SetAsDefault(_) => "Set as default".into(),
 			ContextMenuOption::InputExactVolume => "Input exact volume value".into(),
+			ContextMenuOption::ShowDetails => "Details".into(),
+			ContextMenuOption::ApplyScene(name) => format!("Apply scene {}", name),
+			ContextMenuOption::UnloadModule => "Unload".into(),
+			ContextMenuOption::LoadModule => "Load module".into(),
+			ContextMenuOption::CreateNullSink => "Create null sink".into(),
+			ContextMenuOption::CreateLoopback => "Create loopback from a source".into(),
+			ContextMenuOption::CombineSinks => "Combine with other sinks".into(),
+			ContextMenuOption::UnloadCreatedModules => "Unload modules loaded by rsmixer".into(),
+			ContextMenuOption::ChangePort => "Change port".into(),
+			ContextMenuOption::SetPort(_, s, _) => s,
+		}
+	}
+}
+
+impl ContextMenuOption {
+	// unavailable options are greyed out, but can still be chosen
+	pub fn is_available(&self) -> bool {
+		match self {
+			ContextMenuOption::ChangeCardProfile(_, _, available)
+			| ContextMenuOption::SetPort(_, _, available) => *available,
+			_ => true,
 		}
 	}
 }
@@ -36,6 +71,10 @@ impl From<ContextMenuOption> for String {
 pub enum ContextMenuEffect {
 	None,
 	MoveEntry,
+	ChangePort,
+	Kill,
+	// sent to pulseaudio through the state, so that it can be undone
+	Dispatch(PulseAudioAction),
 }
 
 scrollable!(
@@ -69,41 +108,73 @@ pub struct ContextMenu {
 }
 
 impl ContextMenu {
-	pub fn new(entry: &Entry) -> Self {
-		let play = match &entry.entry_kind {
-			EntryKind::PlayEntry(play) => Some(play),
-			EntryKind::CardEntry(_) => None,
-		};
-		let card = match &entry.entry_kind {
-			EntryKind::PlayEntry(_) => None,
-			EntryKind::CardEntry(card) => Some(card),
-		};
-		let options: Vec<ContextMenuOption> = match entry.entry_type {
+	pub fn new(entry: &Entry, has_created_modules: bool, scenes: &Scenes) -> Self {
+		let play = entry.entry_kind.play_entry();
+		let card = entry.entry_kind.card_entry();
+		let mut options: Vec<ContextMenuOption> = match entry.entry_type {
 			EntryType::Source | EntryType::Sink => vec![
 				if play.unwrap().suspended {
 					ContextMenuOption::Resume
 				} else {
 					ContextMenuOption::Suspend
 				},
-				ContextMenuOption::SetAsDefault,
+				ContextMenuOption::SetAsDefault(play.unwrap().device_name.clone()),
 				ContextMenuOption::InputExactVolume,
-			],
+				ContextMenuOption::ShowDetails,
+			]
+			.into_iter()
+			.chain(
+				play.filter(|p| p.ports.len() > 1)
+					.map(|_| ContextMenuOption::ChangePort),
+			)
+			.chain(virtual_device_options(entry.entry_type))
+			.chain(scene_options(scenes))
+			.collect(),
 			EntryType::SinkInput => vec![
 				ContextMenuOption::Move,
 				ContextMenuOption::Kill,
 				ContextMenuOption::InputExactVolume,
+				ContextMenuOption::ShowDetails,
 			],
-			EntryType::SourceOutput => vec![ContextMenuOption::InputExactVolume],
-			EntryType::Card => card
-				.unwrap()
-				.profiles
-				.iter()
-				.map(|p| {
-					ContextMenuOption::ChangeCardProfile(p.name.clone(), p.description.clone())
-				})
-				.collect(),
+			EntryType::SourceOutput => vec![
+				ContextMenuOption::InputExactVolume,
+				ContextMenuOption::ShowDetails,
+			],
+			EntryType::Card => {
+				let card = card.unwrap();
+
+				card.profiles
+					.iter()
+					.enumerate()
+					.map(|(i, p)| {
+						let label = if card.selected_profile == Some(i) {
+							format!("{} (active)", p.label())
+						} else {
+							p.label()
+						};
+
+						ContextMenuOption::ChangeCardProfile(
+							p.name.clone(),
+							label,
+							p.is_available(),
+						)
+					})
+					.collect()
+			}
+			EntryType::Module => vec![
+				ContextMenuOption::UnloadModule,
+				ContextMenuOption::LoadModule,
+			],
+			// moves all streams of the group
+			EntryType::Client => vec![ContextMenuOption::Move],
 		};
 
+		if has_created_modules {
+			if let EntryType::Sink | EntryType::Module = entry.entry_type {
+				options.push(ContextMenuOption::UnloadCreatedModules);
+			}
+		}
+
 		Self {
 			options,
 			selected: 0,
@@ -114,36 +185,101 @@ impl ContextMenu {
 		}
 	}
 
+	// submenu of a sink or source, with the active port selected
+	pub fn ports(entry: &Entry) -> Self {
+		let play = entry.entry_kind.play_entry();
+
+		Self {
+			options: play
+				.map(|p| {
+					p.ports
+						.iter()
+						.map(|p| {
+							ContextMenuOption::SetPort(
+								p.name.clone(),
+								p.description.clone(),
+								p.available,
+							)
+						})
+						.collect()
+				})
+				.unwrap_or_default(),
+			selected: play.and_then(|p| p.active_port).unwrap_or(0),
+			entry_ident: EntryIdentifier::new(entry.entry_type, entry.index),
+			..Self::default()
+		}
+	}
+
 	pub fn resolve(&self, ident: EntryIdentifier, ctx: &Ctx) -> ContextMenuEffect {
 		match &self.options[self.selected] {
 			ContextMenuOption::Move => {
 				return ContextMenuEffect::MoveEntry;
 			}
+			ContextMenuOption::ChangePort => {
+				return ContextMenuEffect::ChangePort;
+			}
+			ContextMenuOption::SetPort(name, _, _) => {
+				return ContextMenuEffect::Dispatch(PulseAudioAction::SetPort(ident, name.clone()));
+			}
 			ContextMenuOption::InputExactVolume => {
 				ctx.send_to("event_loop", UserAction::InputVolumeValue);
 			}
-			ContextMenuOption::MoveToEntry(entry, _) => {
+			ContextMenuOption::ShowDetails => {
+				ctx.send_to("event_loop", UserAction::ShowDetails);
+			}
+			ContextMenuOption::ApplyScene(name) => {
+				ctx.send_to("event_loop", UserAction::ApplyScene(name.clone()));
+			}
+			ContextMenuOption::LoadModule => {
+				ctx.send_to("event_loop", UserAction::LoadModule);
+			}
+			ContextMenuOption::CreateNullSink => {
+				ctx.send_to("event_loop", UserAction::CreateNullSink);
+			}
+			ContextMenuOption::CreateLoopback => {
 				ctx.send_to(
-					"pulseaudio",
-					PulseAudioAction::MoveEntryToParent(ident, *entry),
+					"event_loop",
+					UserAction::StartPicking(VirtualDevice::Loopback(ident)),
 				);
 			}
-			ContextMenuOption::ChangeCardProfile(name, _) => {
+			ContextMenuOption::CombineSinks => {
 				ctx.send_to(
-					"pulseaudio",
-					PulseAudioAction::ChangeCardProfile(ident, name.clone()),
+					"event_loop",
+					UserAction::StartPicking(VirtualDevice::Combine),
 				);
 			}
+			ContextMenuOption::UnloadCreatedModules => {
+				ctx.send_to("event_loop", UserAction::UnloadCreatedModules);
+			}
+			ContextMenuOption::UnloadModule => {
+				return ContextMenuEffect::Dispatch(PulseAudioAction::UnloadModule(ident));
+			}
+			ContextMenuOption::MoveToEntry(entry, _) => {
+				return ContextMenuEffect::Dispatch(PulseAudioAction::MoveEntryToParent(
+					ident, *entry,
+				));
+			}
+			ContextMenuOption::ChangeCardProfile(name, _, _) => {
+				return ContextMenuEffect::Dispatch(PulseAudioAction::ChangeCardProfile(
+					ident,
+					name.clone(),
+				));
+			}
 			ContextMenuOption::Suspend => {
-				ctx.send_to("pulseaudio", PulseAudioAction::SetSuspend(ident, true));
+				return ContextMenuEffect::Dispatch(PulseAudioAction::SetSuspend(ident, true));
 			}
 			ContextMenuOption::Resume => {
-				ctx.send_to("pulseaudio", PulseAudioAction::SetSuspend(ident, false));
+				return ContextMenuEffect::Dispatch(PulseAudioAction::SetSuspend(ident, false));
 			}
 			ContextMenuOption::Kill => {
-				ctx.send_to("pulseaudio", PulseAudioAction::KillEntry(ident));
+				return ContextMenuEffect::Kill;
+			}
+			ContextMenuOption::SetAsDefault(name) => {
+				return ContextMenuEffect::Dispatch(PulseAudioAction::SetDefault(
+					ident,
+					name.clone(),
+				));
 			}
-			_ => {}
 		};
 
 		ContextMenuEffect::None
@@ -161,10 +297,33 @@ impl ContextMenu {
 
 		match longest {
 			None => 0,
-			Some(l) => l / self.area.width as usize,
+			// an option exactly as wide as the menu fits without scrolling
+			Some(l) => l.saturating_sub(1) / self.area.width as usize,
 		}
 	}
 }
+
+// virtual devices are created from the output page
+fn virtual_device_options(entry_type: EntryType) -> Vec<ContextMenuOption> {
+	if entry_type != EntryType::Sink {
+		return Vec::new();
+	}
+
+	vec![
+		ContextMenuOption::CreateNullSink,
+		ContextMenuOption::CreateLoopback,
+		ContextMenuOption::CombineSinks,
+	]
+}
+
+// scenes apply to every entry, so they're listed in menus of sinks and sources
+fn scene_options(scenes: &Scenes) -> Vec<ContextMenuOption> {
+	scenes
+		.names()
+		.map(|name| ContextMenuOption::ApplyScene(name.clone()))
+		.collect()
+}
+
 impl Default for ContextMenu {
 	fn default() -> Self {
 		Self {
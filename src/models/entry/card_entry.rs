@@ -1,9 +1,15 @@
+use super::Port;
 use crate::ui::Rect;
 
 #[derive(PartialEq, Clone, Debug)]
 pub struct CardProfile {
 	pub name: String,
 	pub description: String,
+	// number of sinks and sources the profile would create
+	pub n_sinks: u32,
+	pub n_sources: u32,
+	// the higher, the more useful the profile is as a default
+	pub priority: u32,
 	#[cfg(any(feature = "pa_v13"))]
 	pub available: bool,
 	pub area: Rect,
@@ -11,12 +17,45 @@ pub struct CardProfile {
 }
 impl Eq for CardProfile {}
 
+impl CardProfile {
+	// without pa_v13 availability is unknown, so every profile is available
+	pub fn is_available(&self) -> bool {
+		#[cfg(feature = "pa_v13")]
+		return self.available;
+		#[cfg(not(feature = "pa_v13"))]
+		return true;
+	}
+
+	// description with the number of devices, e.g. "Analog Stereo Duplex (1 sink, 1 source)"
+	pub fn label(&self) -> String {
+		let plural = |n: u32, what: &str| match n {
+			1 => format!("1 {}", what),
+			n => format!("{} {}s", n, what),
+		};
+
+		let devices = [(self.n_sinks, "sink"), (self.n_sources, "source")]
+			.iter()
+			.filter(|(n, _)| *n > 0)
+			.map(|(n, what)| plural(*n, what))
+			.collect::<Vec<_>>();
+
+		if devices.is_empty() {
+			self.description.clone()
+		} else {
+			format!("{} ({})", self.description, devices.join(", "))
+		}
+	}
+}
+
 #[derive(PartialEq, Clone, Debug)]
 pub struct CardEntry {
 	pub profiles: Vec<CardProfile>,
 	pub selected_profile: Option<usize>,
+	// ports of all sinks and sources of the card
+	pub ports: Vec<Port>,
 	pub area: Rect,
 	pub is_selected: bool,
+	pub highlighted: Vec<usize>,
 	pub name: String,
 }
 impl Eq for CardEntry {}
@@ -0,0 +1,22 @@
+use crate::ui::Rect;
+
+// an application connected to pulseaudio, shown above its streams
+// when it has more than one of them on a sink or source
+#[derive(PartialEq, Clone, Debug)]
+pub struct ClientEntry {
+	pub name: String,
+	pub binary: Option<String>,
+	pub pid: Option<u32>,
+	// sink or source the streams of the group play to, set when the page is generated
+	pub parent: Option<u32>,
+	pub streams: usize,
+	// nothing else is below the group on its sink or source
+	pub last: bool,
+	// every stream of the group is muted
+	pub mute: bool,
+	pub area: Rect,
+	pub is_selected: bool,
+	pub is_marked: bool,
+	pub highlighted: Vec<usize>,
+}
+impl Eq for ClientEntry {}
@@ -1,6 +1,6 @@
 use std::collections::BTreeMap;
 
-use super::{CardEntry, Entry, EntryIdentifier, EntryType, PlayEntry};
+use super::{CardEntry, ClientEntry, Entry, EntryIdentifier, EntryType, ModuleEntry, PlayEntry};
 
 pub struct Entries(BTreeMap<EntryIdentifier, Entry>);
 
@@ -42,6 +42,16 @@ impl Entries {
 			.get(entry_ident)
 			.and_then(|e| e.entry_kind.card_entry())
 	}
+	pub fn get_module_entry(&self, entry_ident: &EntryIdentifier) -> Option<&ModuleEntry> {
+		self.0
+			.get(entry_ident)
+			.and_then(|e| e.entry_kind.module_entry())
+	}
+	pub fn get_client_entry(&self, entry_ident: &EntryIdentifier) -> Option<&ClientEntry> {
+		self.0
+			.get(entry_ident)
+			.and_then(|e| e.entry_kind.client_entry())
+	}
 	pub fn get_play_entry(&self, entry_ident: &EntryIdentifier) -> Option<&PlayEntry> {
 		self.0
 			.get(entry_ident)
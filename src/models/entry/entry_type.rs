@@ -5,6 +5,8 @@ pub enum EntryType {
 	Source,
 	SourceOutput,
 	Card,
+	Module,
+	Client,
 }
 
 impl From<EntryType> for u8 {
@@ -15,6 +17,8 @@ impl From<EntryType> for u8 {
 			EntryType::SinkInput => 3,
 			EntryType::SourceOutput => 4,
 			EntryType::Card => 5,
+			EntryType::Module => 6,
+			EntryType::Client => 7,
 		}
 	}
 }
@@ -33,3 +37,18 @@ impl std::cmp::Ord for EntryType {
 		a.cmp(&b)
 	}
 }
+
+impl EntryType {
+	// as shown to the user
+	pub fn name(&self) -> &'static str {
+		match self {
+			EntryType::Sink => "sink",
+			EntryType::SinkInput => "sink input",
+			EntryType::Source => "source",
+			EntryType::SourceOutput => "source output",
+			EntryType::Card => "card",
+			EntryType::Module => "module",
+			EntryType::Client => "client",
+		}
+	}
+}
@@ -1,18 +1,29 @@
 mod card_entry;
+mod client_entry;
 mod entries;
 mod entry_type;
 mod identifier;
+mod module_entry;
 mod play_entry;
+mod port;
 
 pub use card_entry::{CardEntry, CardProfile};
+pub use client_entry::ClientEntry;
 pub use entries::Entries;
 pub use entry_type::EntryType;
 pub use identifier::EntryIdentifier;
+pub use module_entry::ModuleEntry;
 pub use play_entry::PlayEntry;
-use pulse::volume::ChannelVolumes;
+pub use port::Port;
+use std::collections::BTreeMap;
+
+use pulse::{channelmap::Map, volume::ChannelVolumes};
 
 use crate::{
-	ui::{widgets::VolumeWidget, Rect},
+	ui::{
+		widgets::{MeterWidget, VolumeWidget},
+		Rect,
+	},
 	unwrap_or_return,
 };
 
@@ -23,38 +34,62 @@ pub enum EntrySpaceLvl {
 	ParentNoChildren,
 	MidChild,
 	LastChild,
+	// stream grouped under its client, `parent_continues` when
+	// the sink or source has more children below the group
+	ClientChild { last: bool, parent_continues: bool },
 	Card,
 }
 
 #[derive(PartialEq, Clone, Debug)]
+#[allow(clippy::enum_variant_names)]
 pub enum EntryKind {
 	CardEntry(CardEntry),
-	PlayEntry(PlayEntry),
+	PlayEntry(Box<PlayEntry>),
+	ModuleEntry(ModuleEntry),
+	ClientEntry(ClientEntry),
 }
 
 impl EntryKind {
 	pub fn play_entry(&self) -> Option<&PlayEntry> {
 		match self {
 			Self::PlayEntry(play) => Some(play),
-			Self::CardEntry(_) => None,
+			_ => None,
 		}
 	}
 	pub fn card_entry(&self) -> Option<&CardEntry> {
 		match self {
 			Self::CardEntry(card) => Some(card),
-			Self::PlayEntry(_) => None,
+			_ => None,
+		}
+	}
+	pub fn module_entry(&self) -> Option<&ModuleEntry> {
+		match self {
+			Self::ModuleEntry(module) => Some(module),
+			_ => None,
+		}
+	}
+	pub fn client_entry(&self) -> Option<&ClientEntry> {
+		match self {
+			Self::ClientEntry(client) => Some(client),
+			_ => None,
+		}
+	}
+	pub fn client_entry_mut(&mut self) -> Option<&mut ClientEntry> {
+		match self {
+			Self::ClientEntry(client) => Some(client),
+			_ => None,
 		}
 	}
 	pub fn play_entry_mut(&mut self) -> Option<&mut PlayEntry> {
 		match self {
 			Self::PlayEntry(play) => Some(play),
-			Self::CardEntry(_) => None,
+			_ => None,
 		}
 	}
 	pub fn card_entry_mut(&mut self) -> Option<&mut CardEntry> {
 		match self {
 			Self::CardEntry(card) => Some(card),
-			Self::PlayEntry(_) => None,
+			_ => None,
 		}
 	}
 }
@@ -93,7 +128,9 @@ pub struct Entry {
 	pub index: u32,
 	pub name: String,
 	pub is_selected: bool,
+	pub is_marked: bool,
 	pub position: EntrySpaceLvl,
+	pub highlighted: Vec<usize>,
 	pub entry_kind: EntryKind,
 }
 impl Eq for Entry {}
@@ -106,10 +143,10 @@ impl Entry {
 	}
 
 	pub fn parent(&self) -> Option<u32> {
-		if let EntryKind::PlayEntry(play) = &self.entry_kind {
-			play.parent
-		} else {
-			None
+		match &self.entry_kind {
+			EntryKind::PlayEntry(play) => play.parent,
+			EntryKind::ClientEntry(client) => client.parent,
+			_ => None,
 		}
 	}
 
@@ -120,6 +157,7 @@ impl Entry {
 		parent: Option<u32>,
 		mute: bool,
 		volume: ChannelVolumes,
+		channel_map: Map,
 		monitor_source: Option<u32>,
 		sink: Option<u32>,
 		suspended: bool,
@@ -130,24 +168,80 @@ impl Entry {
 			index,
 			name: name.clone(),
 			is_selected: false,
+			is_marked: false,
 			position: EntrySpaceLvl::Empty,
-			entry_kind: EntryKind::PlayEntry(PlayEntry {
-				peak: 0.0,
+			highlighted: Vec::new(),
+			entry_kind: EntryKind::PlayEntry(Box::new(PlayEntry {
+				meters: Vec::new(),
 				mute,
 				parent,
+				client: None,
 				volume,
+				channel_map,
 				monitor_source,
 				sink,
 				volume_bar: VolumeWidget::default(),
-				peak_volume_bar: VolumeWidget::default(),
+				max_volume: 150,
+				peak_volume_bar: MeterWidget::default(),
 				suspended,
 				area: Rect::default(),
 				name,
+				device_name: String::new(),
 				is_selected: false,
+				is_default: false,
+				is_picked: false,
+				is_marked: false,
+				ports: Vec::new(),
+				active_port: None,
+				highlighted: Vec::new(),
 				position: EntrySpaceLvl::Empty,
 				hidden: HiddenStatus::Show,
-			}),
+				properties: BTreeMap::new(),
+				details: Vec::new(),
+			})),
+		}
+	}
+
+	pub fn properties(mut self, properties: BTreeMap<String, String>) -> Self {
+		if let EntryKind::PlayEntry(play) = &mut self.entry_kind {
+			play.properties = properties;
 		}
+		self
+	}
+
+	pub fn device_name(mut self, device_name: String) -> Self {
+		if let EntryKind::PlayEntry(play) = &mut self.entry_kind {
+			play.device_name = device_name;
+		}
+		self
+	}
+
+	pub fn client(mut self, client: Option<u32>) -> Self {
+		if let EntryKind::PlayEntry(play) = &mut self.entry_kind {
+			play.client = client;
+		}
+		self
+	}
+
+	pub fn details(mut self, details: Vec<(String, String)>) -> Self {
+		if let EntryKind::PlayEntry(play) = &mut self.entry_kind {
+			play.details = details;
+		}
+		self
+	}
+
+	pub fn ports(mut self, ports: Vec<Port>, active_port: Option<usize>) -> Self {
+		match &mut self.entry_kind {
+			EntryKind::PlayEntry(play) => {
+				play.ports = ports;
+				play.active_port = active_port;
+			}
+			EntryKind::CardEntry(card) => {
+				card.ports = ports;
+			}
+			_ => {}
+		}
+		self
 	}
 
 	pub fn new_card_entry(
@@ -162,13 +256,68 @@ impl Entry {
 			index,
 			name: name.clone(),
 			is_selected: false,
+			is_marked: false,
 			position: EntrySpaceLvl::Card,
+			highlighted: Vec::new(),
 			entry_kind: EntryKind::CardEntry(CardEntry {
 				area: Rect::default(),
 				is_selected: false,
 				profiles,
 				selected_profile,
+				ports: Vec::new(),
+				highlighted: Vec::new(),
+				name,
+			}),
+		}
+	}
+
+	pub fn new_module_entry(index: u32, name: String, argument: String) -> Self {
+		Self {
+			entry_ident: EntryIdentifier::new(EntryType::Module, index),
+			entry_type: EntryType::Module,
+			index,
+			name: name.clone(),
+			is_selected: false,
+			is_marked: false,
+			position: EntrySpaceLvl::Card,
+			highlighted: Vec::new(),
+			entry_kind: EntryKind::ModuleEntry(ModuleEntry {
+				argument,
+				area: Rect::default(),
+				is_selected: false,
+				highlighted: Vec::new(),
+				name,
+			}),
+		}
+	}
+
+	pub fn new_client_entry(
+		index: u32,
+		name: String,
+		binary: Option<String>,
+		pid: Option<u32>,
+	) -> Self {
+		Self {
+			entry_ident: EntryIdentifier::new(EntryType::Client, index),
+			entry_type: EntryType::Client,
+			index,
+			name: name.clone(),
+			is_selected: false,
+			is_marked: false,
+			position: EntrySpaceLvl::Empty,
+			highlighted: Vec::new(),
+			entry_kind: EntryKind::ClientEntry(ClientEntry {
 				name,
+				binary,
+				pid,
+				parent: None,
+				streams: 0,
+				last: false,
+				mute: false,
+				area: Rect::default(),
+				is_selected: false,
+				is_marked: false,
+				highlighted: Vec::new(),
 			}),
 		}
 	}
@@ -178,6 +327,7 @@ impl Entry {
 			EntrySpaceLvl::Card => 1,
 			EntrySpaceLvl::Parent => 2,
 			EntrySpaceLvl::ParentNoChildren => 2,
+			EntrySpaceLvl::ClientChild { .. } => 9,
 			_ => 5,
 		};
 
@@ -217,12 +367,21 @@ impl Entry {
 				let old_card = unwrap_or_return!(entries.get_card_entry(&self.entry_ident), true);
 				old_card.name != card.name || old_card.selected_profile != card.selected_profile
 			}
+			EntryKind::ModuleEntry(module) => {
+				let old_module =
+					unwrap_or_return!(entries.get_module_entry(&self.entry_ident), true);
+				old_module.name != module.name || old_module.argument != module.argument
+			}
+			EntryKind::ClientEntry(client) => {
+				let old_client =
+					unwrap_or_return!(entries.get_client_entry(&self.entry_ident), true);
+				old_client.name != client.name
+			}
 			EntryKind::PlayEntry(play) => {
 				let old_play = unwrap_or_return!(entries.get_play_entry(&self.entry_ident), true);
 				old_play.name != play.name
 					|| old_play.mute != play.mute
 					|| old_play.volume != play.volume
-					|| (play.peak - old_play.peak).abs() < f32::EPSILON
 			}
 		}
 	}
@@ -234,11 +393,26 @@ impl Entry {
 					card.area = old_card.area;
 				}
 			}
+			EntryKind::ModuleEntry(module) => {
+				if let Some(old_module) = entries.get_module_entry(&self.entry_ident) {
+					module.area = old_module.area;
+				}
+			}
+			EntryKind::ClientEntry(client) => {
+				if let Some(old_client) = entries.get_client_entry(&self.entry_ident) {
+					client.area = old_client.area;
+					client.parent = old_client.parent;
+					client.streams = old_client.streams;
+					client.last = old_client.last;
+					client.mute = old_client.mute;
+				}
+			}
 			EntryKind::PlayEntry(play) => {
 				if let Some(old_play) = entries.get_play_entry(&self.entry_ident) {
 					play.area = old_play.area;
 					play.volume_bar = old_play.volume_bar;
-					play.peak_volume_bar = old_play.peak_volume_bar;
+					play.peak_volume_bar = old_play.peak_volume_bar.clone();
+					play.meters = old_play.meters.clone();
 				}
 			}
 		};
@@ -248,6 +422,8 @@ impl Entry {
 		match &self.entry_kind {
 			EntryKind::CardEntry(card) => card.area,
 			EntryKind::PlayEntry(play) => play.area,
+			EntryKind::ModuleEntry(module) => module.area,
+			EntryKind::ClientEntry(client) => client.area,
 		}
 	}
 }
@@ -0,0 +1,11 @@
+use crate::ui::Rect;
+
+#[derive(PartialEq, Clone, Debug)]
+pub struct ModuleEntry {
+	pub argument: String,
+	pub area: Rect,
+	pub is_selected: bool,
+	pub highlighted: Vec<usize>,
+	pub name: String,
+}
+impl Eq for ModuleEntry {}
@@ -1,23 +1,50 @@
-use pulse::volume::ChannelVolumes;
+use std::collections::BTreeMap;
 
-use super::{EntrySpaceLvl, HiddenStatus};
-use crate::ui::{widgets::VolumeWidget, Rect};
+use pulse::{channelmap::Map, volume::ChannelVolumes};
+
+use super::{EntrySpaceLvl, HiddenStatus, Port};
+use crate::{
+	models::LevelMeter,
+	ui::{
+		widgets::{MeterWidget, VolumeWidget},
+		Rect,
+	},
+};
 
 #[derive(PartialEq, Clone, Debug)]
 pub struct PlayEntry {
-	pub peak: f32,
+	// one for every channel the monitor records
+	pub meters: Vec<LevelMeter>,
 	pub mute: bool,
 	pub volume: ChannelVolumes,
+	pub channel_map: Map,
 	pub monitor_source: Option<u32>,
 	pub sink: Option<u32>,
 	pub volume_bar: VolumeWidget,
-	pub peak_volume_bar: VolumeWidget,
+	// in percent, set before rendering
+	pub max_volume: u16,
+	pub peak_volume_bar: MeterWidget,
 	pub suspended: bool,
 	pub area: Rect,
 	pub name: String,
+	// name of a sink or source in pulseaudio, the name above is its description
+	pub device_name: String,
 	pub is_selected: bool,
+	pub is_default: bool,
+	// picked as a part of a virtual device
+	pub is_picked: bool,
+	// marked for an action on several entries at once
+	pub is_marked: bool,
+	pub highlighted: Vec<usize>,
 	pub position: EntrySpaceLvl,
 	pub hidden: HiddenStatus,
 	pub parent: Option<u32>,
+	// client owning a sink input or source output
+	pub client: Option<u32>,
+	pub properties: BTreeMap<String, String>,
+	// sample spec, latency, driver and such, in the order they're shown
+	pub details: Vec<(String, String)>,
+	pub ports: Vec<Port>,
+	pub active_port: Option<usize>,
 }
 impl Eq for PlayEntry {}
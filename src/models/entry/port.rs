@@ -0,0 +1,8 @@
+#[derive(PartialEq, Clone, Debug)]
+pub struct Port {
+	pub name: String,
+	pub description: String,
+	// false when pulseaudio knows nothing is plugged in
+	pub available: bool,
+}
+impl Eq for Port {}
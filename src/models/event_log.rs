@@ -0,0 +1,155 @@
+use std::{collections::VecDeque, time::SystemTime};
+
+use crate::entry::{EntryIdentifier, EntryType};
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum EventKind {
+	Added,
+	Changed,
+	Removed,
+	DefaultChanged,
+	Disconnected,
+	Connecting,
+}
+
+impl EventKind {
+	// as shown on the log page
+	pub fn name(&self) -> &'static str {
+		match self {
+			Self::Added => "new",
+			Self::Changed => "changed",
+			Self::Removed => "removed",
+			Self::DefaultChanged => "default",
+			Self::Disconnected => "disconnected",
+			Self::Connecting => "connecting",
+		}
+	}
+}
+
+// something that happened on the server, described for the user
+#[derive(Clone, PartialEq, Debug)]
+pub struct Event {
+	pub time: SystemTime,
+	pub kind: EventKind,
+	// None for events of the connection itself
+	pub ident: Option<EntryIdentifier>,
+	pub name: String,
+}
+
+impl Event {
+	pub fn new(kind: EventKind, ident: EntryIdentifier, name: &str) -> Self {
+		Self {
+			time: SystemTime::now(),
+			kind,
+			ident: Some(ident),
+			name: name.to_string(),
+		}
+	}
+
+	pub fn connection(kind: EventKind) -> Self {
+		Self {
+			time: SystemTime::now(),
+			kind,
+			ident: None,
+			name: String::new(),
+		}
+	}
+
+	// devices coming and going, streams and modules do that all the time
+	pub fn is_device(ident: &EntryIdentifier) -> bool {
+		matches!(
+			ident.entry_type,
+			EntryType::Sink | EntryType::Source | EntryType::Card
+		)
+	}
+
+	pub fn text(&self) -> String {
+		let type_name = self.ident.map(|i| i.entry_type.name()).unwrap_or("entry");
+
+		match self.kind {
+			EventKind::Added => format!("{} '{}' added", capitalize(type_name), self.name),
+			EventKind::Changed => format!("{} '{}' changed", capitalize(type_name), self.name),
+			EventKind::Removed => format!("{} '{}' removed", capitalize(type_name), self.name),
+			EventKind::DefaultChanged if self.name.is_empty() => {
+				format!("Default {} changed", type_name)
+			}
+			EventKind::DefaultChanged => {
+				format!("Default {} changed to '{}'", type_name, self.name)
+			}
+			EventKind::Disconnected => "Disconnected from PulseAudio".to_string(),
+			EventKind::Connecting => "Connecting to PulseAudio".to_string(),
+		}
+	}
+}
+
+fn capitalize(s: &str) -> String {
+	let mut chars = s.chars();
+	match chars.next() {
+		Some(first) => first.to_uppercase().chain(chars).collect(),
+		None => String::new(),
+	}
+}
+
+// which events the log page shows
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum LogFilter {
+	All,
+	Type(EntryType),
+	Connection,
+}
+
+const FILTERS: [LogFilter; 9] = [
+	LogFilter::All,
+	LogFilter::Type(EntryType::Sink),
+	LogFilter::Type(EntryType::SinkInput),
+	LogFilter::Type(EntryType::Source),
+	LogFilter::Type(EntryType::SourceOutput),
+	LogFilter::Type(EntryType::Card),
+	LogFilter::Type(EntryType::Module),
+	LogFilter::Type(EntryType::Client),
+	LogFilter::Connection,
+];
+
+impl LogFilter {
+	pub fn matches(&self, event: &Event) -> bool {
+		match self {
+			Self::All => true,
+			Self::Type(entry_type) => event.ident.map(|i| i.entry_type) == Some(*entry_type),
+			Self::Connection => event.ident.is_none(),
+		}
+	}
+
+	pub fn cycle(&self, which_way: i8) -> Self {
+		let i = FILTERS.iter().position(|f| f == self).unwrap_or(0) as i8;
+		let len = FILTERS.len() as i8;
+
+		FILTERS[((i + which_way).rem_euclid(len)) as usize]
+	}
+
+	pub fn name(&self) -> String {
+		match self {
+			Self::All => "all events".to_string(),
+			Self::Type(entry_type) => format!("{} events", entry_type.name()),
+			Self::Connection => "connection events".to_string(),
+		}
+	}
+}
+
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct EventLog {
+	events: VecDeque<Event>,
+}
+
+impl EventLog {
+	// the oldest events are dropped to keep at most `max` of them
+	pub fn push(&mut self, event: Event, max: usize) {
+		self.events.push_back(event);
+		while self.events.len() > max {
+			self.events.pop_front();
+		}
+	}
+
+	pub fn iter(&self) -> impl Iterator<Item = &Event> {
+		self.events.iter()
+	}
+}
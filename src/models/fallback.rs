@@ -0,0 +1,123 @@
+use std::collections::HashSet;
+
+use super::PulseAudioAction;
+use crate::entry::{Entries, Entry, EntryIdentifier, EntryType};
+
+// sinks and sources are ranked by the [fallback] lists of the config, most
+// preferred first. streams left behind by a removed device go to the best one
+// still present, and come back when a better one appears
+
+fn child_type(entry_type: EntryType) -> Option<EntryType> {
+	match entry_type {
+		EntryType::Sink => Some(EntryType::SinkInput),
+		EntryType::Source => Some(EntryType::SourceOutput),
+		_ => None,
+	}
+}
+
+// position in the list, matched by description (ignoring case) or by name
+fn rank(entry: &Entry, preferred: &[String]) -> Option<usize> {
+	let name = entry
+		.entry_kind
+		.play_entry()
+		.map(|p| p.device_name.as_str());
+
+	preferred
+		.iter()
+		.position(|p| p.eq_ignore_ascii_case(&entry.name) || Some(p.as_str()) == name)
+}
+
+fn device_name(entry: &Entry) -> String {
+	entry
+		.entry_kind
+		.play_entry()
+		.map(|p| p.device_name.clone())
+		.unwrap_or_default()
+}
+
+fn rank_of(entries: &Entries, ident: &EntryIdentifier, preferred: &[String]) -> Option<usize> {
+	entries.get(ident).and_then(|e| rank(e, preferred))
+}
+
+// moves the streams of a removed device, and the default if it was the
+// default, to the best device left. `entries` no longer has the device
+pub fn reroute_from(
+	entries: &Entries,
+	defaults: &HashSet<EntryIdentifier>,
+	removed: &EntryIdentifier,
+	preferred: &[String],
+) -> Vec<PulseAudioAction> {
+	let child = match child_type(removed.entry_type) {
+		Some(child) => child,
+		None => return Vec::new(),
+	};
+
+	let (target, target_name) = match entries
+		.iter_type(removed.entry_type)
+		.filter_map(|(ident, e)| rank(e, preferred).map(|r| (r, *ident, e)))
+		.min_by_key(|(r, _, _)| *r)
+	{
+		Some((_, target, e)) => (target, device_name(e)),
+		None => return Vec::new(),
+	};
+
+	let mut actions = entries
+		.iter_type(child)
+		.filter(|(_, e)| e.parent() == Some(removed.index))
+		.map(|(ident, _)| PulseAudioAction::MoveEntryToParent(*ident, target))
+		.collect::<Vec<_>>();
+
+	if defaults.contains(removed) {
+		actions.push(PulseAudioAction::SetDefault(target, target_name));
+	}
+
+	actions
+}
+
+// moves back the rerouted streams, and the default if it was rerouted too,
+// when a device ranked above the one they're on appears
+pub fn reroute_to(
+	entries: &Entries,
+	defaults: &HashSet<EntryIdentifier>,
+	rerouted: &HashSet<EntryIdentifier>,
+	rerouted_defaults: &HashSet<EntryType>,
+	added: &EntryIdentifier,
+	preferred: &[String],
+) -> Vec<PulseAudioAction> {
+	let child = match child_type(added.entry_type) {
+		Some(child) => child,
+		None => return Vec::new(),
+	};
+	let added_rank = match rank_of(entries, added, preferred) {
+		Some(r) => r,
+		None => return Vec::new(),
+	};
+
+	// devices not on the list rank below all of those on it
+	let outranks = |parent: Option<u32>| match parent
+		.map(|p| EntryIdentifier::new(added.entry_type, p))
+		.and_then(|p| rank_of(entries, &p, preferred))
+	{
+		Some(r) => added_rank < r,
+		None => true,
+	};
+
+	let mut actions = entries
+		.iter_type(child)
+		.filter(|(ident, e)| rerouted.contains(ident) && outranks(e.parent()))
+		.map(|(ident, _)| PulseAudioAction::MoveEntryToParent(*ident, *added))
+		.collect::<Vec<_>>();
+
+	if !rerouted_defaults.contains(&added.entry_type) {
+		return actions;
+	}
+
+	if let Some(default) = defaults.iter().find(|d| d.entry_type == added.entry_type) {
+		if outranks(Some(default.index)) {
+			let name = entries.get(added).map(device_name).unwrap_or_default();
+			actions.push(PulseAudioAction::SetDefault(*added, name));
+		}
+	}
+
+	actions
+}
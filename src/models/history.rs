@@ -0,0 +1,157 @@
+use std::{
+	collections::HashSet,
+	time::{Duration, Instant},
+};
+
+use super::PulseAudioAction;
+use crate::entry::{Entries, EntryIdentifier};
+
+// how many steps are kept for undo
+const MAX_STEPS: usize = 100;
+
+// volume changes closer than this are one step, longer than the delay
+// before a held key starts repeating
+const MERGE_WINDOW: Duration = Duration::from_millis(1000);
+
+// actions sent to pulseaudio for one user action, with the ones undoing them
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Step {
+	pub actions: Vec<PulseAudioAction>,
+	pub inverses: Vec<PulseAudioAction>,
+	// when the step was last committed, None while it's pending
+	time: Option<Instant>,
+	// steps back from redo are never merged into
+	redone: bool,
+}
+
+impl Step {
+	fn is_empty(&self) -> bool {
+		self.actions.is_empty()
+	}
+
+	// entries whose volume is set by every action of the step, if that's all it does
+	fn volume_of(&self) -> Option<Vec<EntryIdentifier>> {
+		self.actions
+			.iter()
+			.map(|a| match a {
+				PulseAudioAction::SetVolume(ident, _) => Some(*ident),
+				_ => None,
+			})
+			.collect()
+	}
+}
+
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct History {
+	undo: Vec<Step>,
+	redo: Vec<Step>,
+	// recorded while the current user action is handled
+	pending: Step,
+}
+
+impl History {
+	pub fn record(&mut self, action: PulseAudioAction, inverse: PulseAudioAction) {
+		self.pending.actions.push(action);
+		self.pending.inverses.push(inverse);
+	}
+
+	// closes the step of the handled user action
+	pub fn commit(&mut self, now: Instant) {
+		if self.pending.is_empty() {
+			return;
+		}
+		let mut step = std::mem::take(&mut self.pending);
+		step.time = Some(now);
+		self.redo.clear();
+
+		// holding a volume key is undone at once, back to the volume before the first press
+		if let Some(last) = self.undo.last_mut() {
+			let repeated = match last.time {
+				Some(time) => !last.redone && now.duration_since(time) < MERGE_WINDOW,
+				None => false,
+			};
+
+			if repeated && step.volume_of().is_some() && step.volume_of() == last.volume_of() {
+				last.actions = step.actions;
+				last.time = step.time;
+				return;
+			}
+		}
+
+		self.undo.push(step);
+		if self.undo.len() > MAX_STEPS {
+			self.undo.remove(0);
+		}
+	}
+
+	// actions undoing the last step, in reverse order
+	pub fn undo(&mut self) -> Option<Vec<PulseAudioAction>> {
+		let step = self.undo.pop()?;
+		let inverses = step.inverses.iter().rev().cloned().collect();
+		self.redo.push(step);
+
+		Some(inverses)
+	}
+
+	pub fn redo(&mut self) -> Option<Vec<PulseAudioAction>> {
+		let mut step = self.redo.pop()?;
+		step.redone = true;
+		let actions = step.actions.clone();
+		self.undo.push(step);
+
+		Some(actions)
+	}
+}
+
+// action restoring what the given one changes, from the current state of the entries,
+// None when the action can't be undone
+pub fn inverse(
+	action: &PulseAudioAction,
+	entries: &Entries,
+	defaults: &HashSet<EntryIdentifier>,
+) -> Option<PulseAudioAction> {
+	match action {
+		PulseAudioAction::MuteEntry(ident, _) => entries
+			.get_play_entry(ident)
+			.map(|p| PulseAudioAction::MuteEntry(*ident, p.mute)),
+		PulseAudioAction::SetVolume(ident, _) => entries
+			.get_play_entry(ident)
+			.map(|p| PulseAudioAction::SetVolume(*ident, p.volume)),
+		PulseAudioAction::MoveEntryToParent(ident, parent) => {
+			let previous = entries.get_play_entry(ident)?.parent?;
+
+			Some(PulseAudioAction::MoveEntryToParent(
+				*ident,
+				EntryIdentifier::new(parent.entry_type, previous),
+			))
+		}
+		PulseAudioAction::ChangeCardProfile(ident, _) => {
+			let card = entries.get_card_entry(ident)?;
+			let profile = card.profiles.get(card.selected_profile?)?;
+
+			Some(PulseAudioAction::ChangeCardProfile(
+				*ident,
+				profile.name.clone(),
+			))
+		}
+		PulseAudioAction::SetPort(ident, _) => {
+			let play = entries.get_play_entry(ident)?;
+			let port = play.ports.get(play.active_port?)?;
+
+			Some(PulseAudioAction::SetPort(*ident, port.name.clone()))
+		}
+		PulseAudioAction::SetSuspend(ident, _) => entries
+			.get_play_entry(ident)
+			.map(|p| PulseAudioAction::SetSuspend(*ident, p.suspended)),
+		PulseAudioAction::SetDefault(ident, _) => {
+			let previous = defaults.iter().find(|d| d.entry_type == ident.entry_type)?;
+			let play = entries.get_play_entry(previous)?;
+
+			Some(PulseAudioAction::SetDefault(
+				*previous,
+				play.device_name.clone(),
+			))
+		}
+		_ => None,
+	}
+}
@@ -90,6 +90,7 @@ impl Display for InputEvent {
 				KeyCode::Null => "Null".to_string(),
 				KeyCode::Esc => "Esc".to_string(),
 				KeyCode::F(i) => format!("F{}", i),
+				KeyCode::Char(' ') => "Space".to_string(),
 				KeyCode::Char(c) => format!("{}", c),
 			},
 			InputEventKind::Mouse(mouse) => match mouse {
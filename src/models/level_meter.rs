@@ -0,0 +1,152 @@
+use std::time::{Duration, Instant};
+
+// levels of one block of samples from a monitor, in dBFS
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Levels {
+	pub peak: f32,
+	pub average: f32,
+}
+
+impl Levels {
+	// monitors are opened with PEAK_DETECT, so every sample pulseaudio sends
+	// is already the peak of a short window, the average is that of their envelope
+	pub fn from_samples(samples: &[f32]) -> Self {
+		if samples.is_empty() {
+			return Self::silence();
+		}
+
+		let peak = samples.iter().fold(0.0f32, |acc, s| acc.max(s.abs()));
+		let average = samples.iter().map(|s| s.abs()).sum::<f32>() / samples.len() as f32;
+
+		Self {
+			peak: to_dbfs(peak),
+			average: to_dbfs(average),
+		}
+	}
+
+	pub fn silence() -> Self {
+		Self {
+			peak: f32::NEG_INFINITY,
+			average: f32::NEG_INFINITY,
+		}
+	}
+}
+
+pub fn to_dbfs(amplitude: f32) -> f32 {
+	if amplitude <= 0.0 {
+		f32::NEG_INFINITY
+	} else {
+		20.0 * amplitude.log10()
+	}
+}
+
+// how the meter follows the levels, set in the [meter] section of the config
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Ballistics {
+	// time constant of a rising level
+	pub attack: Duration,
+	// how fast a falling level drops, in dB per second
+	pub decay: f32,
+	// how long the peak marker stays before it starts falling
+	pub peak_hold: Duration,
+	// level shown as an empty meter, the scale goes from here to 0 dBFS
+	pub floor: f32,
+}
+
+impl Default for Ballistics {
+	fn default() -> Self {
+		Self {
+			attack: Duration::from_millis(10),
+			decay: 20.0,
+			peak_hold: Duration::from_millis(1500),
+			floor: -60.0,
+		}
+	}
+}
+
+// displayed state of a meter, all levels in dBFS and never below the floor
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct LevelMeter {
+	pub peak: f32,
+	pub average: f32,
+	// highest recent peak, drawn as a marker
+	pub hold: f32,
+	held_for: Duration,
+	last_update: Option<Instant>,
+}
+
+impl Default for LevelMeter {
+	fn default() -> Self {
+		Self {
+			peak: f32::NEG_INFINITY,
+			average: f32::NEG_INFINITY,
+			hold: f32::NEG_INFINITY,
+			held_for: Duration::from_secs(0),
+			last_update: None,
+		}
+	}
+}
+
+impl LevelMeter {
+	// returns true when the displayed levels changed
+	pub fn update(&mut self, levels: Levels, now: Instant, ballistics: &Ballistics) -> bool {
+		let before = (self.peak, self.average, self.hold);
+		let floor = ballistics.floor;
+		let peak = levels.peak.max(floor);
+		let average = levels.average.max(floor);
+
+		// the first block is shown as it is
+		let elapsed = match self.last_update {
+			Some(last) => now.saturating_duration_since(last),
+			None => {
+				self.peak = peak;
+				self.average = average;
+				self.hold = peak;
+				self.held_for = Duration::from_secs(0);
+				self.last_update = Some(now);
+				return before != (self.peak, self.average, self.hold);
+			}
+		};
+		self.last_update = Some(now);
+
+		self.peak = follow(self.peak.max(floor), peak, elapsed, ballistics);
+		self.average = follow(self.average.max(floor), average, elapsed, ballistics);
+
+		if peak >= self.hold {
+			self.hold = peak;
+			self.held_for = Duration::from_secs(0);
+		} else {
+			let held_before = self.held_for;
+			self.held_for += elapsed;
+
+			if self.held_for > ballistics.peak_hold {
+				let falling = self.held_for - held_before.max(ballistics.peak_hold);
+				self.hold = (self.hold - ballistics.decay * falling.as_secs_f32())
+					.max(peak)
+					.max(self.peak);
+			}
+		}
+
+		before != (self.peak, self.average, self.hold)
+	}
+
+	// position of a level on the meter, 0.0 at the floor and 1.0 at 0 dBFS
+	pub fn fraction(level: f32, floor: f32) -> f32 {
+		if floor >= 0.0 {
+			return 0.0;
+		}
+		((level - floor) / -floor).clamp(0.0, 1.0)
+	}
+}
+
+fn follow(current: f32, target: f32, elapsed: Duration, ballistics: &Ballistics) -> f32 {
+	if target > current {
+		if ballistics.attack.as_secs_f32() <= 0.0 {
+			return target;
+		}
+		let k = 1.0 - (-elapsed.as_secs_f32() / ballistics.attack.as_secs_f32()).exp();
+		current + (target - current) * k
+	} else {
+		(current - ballistics.decay * elapsed.as_secs_f32()).max(target)
+	}
+}
@@ -1,21 +1,31 @@
 pub mod actions;
 pub mod context_menus;
 pub mod entry;
+mod event_log;
+mod fallback;
+mod history;
 mod input_event;
+mod level_meter;
 mod page_entries;
 mod page_type;
 mod redraw;
 mod state;
 mod style;
 mod ui_mode;
+mod virtual_device;
 
 pub use actions::*;
 pub use context_menus::{ContextMenu, ContextMenuEffect, ContextMenuOption};
+pub use event_log::{Event, EventKind, EventLog, LogFilter};
+pub use fallback::{reroute_from, reroute_to};
+pub use history::{inverse, History};
 pub use input_event::{InputEvent, InputEventKind};
+pub use level_meter::{Ballistics, LevelMeter, Levels};
 pub use page_entries::PageEntries;
 pub use page_type::PageType;
 pub use redraw::Redraw;
 pub use style::Style;
 pub use ui_mode::UIMode;
+pub use virtual_device::{null_sink_module, VirtualDevice};
 
 pub use self::state::RSState;
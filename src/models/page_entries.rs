@@ -97,15 +97,40 @@ scrollable!(
 );
 
 fn calc_lvl(parent_type: EntryType, vs: &[EntryIdentifier], index: usize) -> EntrySpaceLvl {
-	if parent_type == EntryType::Card {
+	let is_client = |i: usize| vs[i].entry_type == EntryType::Client;
+	let is_last = |i: usize| i + 1 >= vs.len() || vs[i + 1].entry_type == parent_type;
+	// first entry after `i` that isn't a stream grouped under a client
+	let next_ungrouped =
+		|i: usize| (i + 1..vs.len()).find(|&j| vs[j].entry_type == parent_type || is_client(j));
+
+	if let EntryType::Card | EntryType::Module = parent_type {
 		EntrySpaceLvl::Card
 	} else if vs[index].entry_type == parent_type {
-		if index + 1 >= vs.len() || vs[index + 1].entry_type == parent_type {
+		if is_last(index) {
 			EntrySpaceLvl::ParentNoChildren
 		} else {
 			EntrySpaceLvl::Parent
 		}
-	} else if index + 1 >= vs.len() || vs[index + 1].entry_type == parent_type {
+	} else if is_client(index) {
+		// streams of the group hang below it, so it only ends
+		// the tree when listed alone while being moved
+		if is_last(index) {
+			EntrySpaceLvl::LastChild
+		} else {
+			EntrySpaceLvl::MidChild
+		}
+	} else if let Some(client) = (0..index)
+		.rev()
+		.find(|&i| vs[i].entry_type == parent_type || is_client(i))
+		.filter(|&i| is_client(i))
+	{
+		let next = next_ungrouped(client);
+
+		EntrySpaceLvl::ClientChild {
+			last: index + 1 == next.unwrap_or(vs.len()),
+			parent_continues: next.is_some_and(is_client),
+		}
+	} else if is_last(index) {
 		EntrySpaceLvl::LastChild
 	} else {
 		EntrySpaceLvl::MidChild
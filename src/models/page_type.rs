@@ -1,13 +1,18 @@
-use std::{fmt::Display, iter};
+use std::{collections::HashSet, fmt::Display, iter};
 
 use super::UIMode;
-use crate::entry::{Entries, Entry, EntryIdentifier, EntryKind, EntryType, HiddenStatus};
+use crate::{
+	entry::{Entries, Entry, EntryIdentifier, EntryKind, EntryType, HiddenStatus},
+	util::filter_match,
+};
 
 #[derive(PartialEq, Clone, Hash, Copy, Debug)]
 pub enum PageType {
 	Output,
 	Input,
 	Cards,
+	Modules,
+	Log,
 }
 impl Eq for PageType {}
 impl Display for PageType {
@@ -21,16 +26,20 @@ impl From<PageType> for i8 {
 			PageType::Output => 0,
 			PageType::Input => 1,
 			PageType::Cards => 2,
+			PageType::Modules => 3,
+			PageType::Log => 4,
 		}
 	}
 }
 impl From<i8> for PageType {
 	fn from(p: i8) -> PageType {
 		match p {
-			-1 => PageType::Cards,
+			-1 => PageType::Log,
 			0 => PageType::Output,
 			1 => PageType::Input,
 			2 => PageType::Cards,
+			3 => PageType::Modules,
+			4 => PageType::Log,
 			_ => PageType::Output,
 		}
 	}
@@ -41,13 +50,22 @@ impl PageType {
 			Self::Output => (EntryType::Sink, EntryType::SinkInput),
 			Self::Input => (EntryType::Source, EntryType::SourceOutput),
 			Self::Cards => (EntryType::Card, EntryType::Card),
+			Self::Modules => (EntryType::Module, EntryType::Module),
+			// nothing is listed on the log page
+			Self::Log => (EntryType::Module, EntryType::Module),
 		}
 	}
+	// pages of sinks, sources and applications, with volume and mute
+	pub fn has_volume(&self) -> bool {
+		matches!(self, Self::Output | Self::Input)
+	}
 	pub fn as_str(&self) -> &'static str {
 		match self {
 			PageType::Output => "Output",
 			PageType::Input => "Input",
 			PageType::Cards => "Cards",
+			PageType::Modules => "Modules",
+			PageType::Log => "Log",
 		}
 	}
 	pub fn as_styled_string(&self) -> String {
@@ -75,9 +93,30 @@ impl PageType {
 		&'a self,
 		entries: &'a Entries,
 		ui_mode: &'a UIMode,
-	) -> Box<dyn Iterator<Item = (&EntryIdentifier, &Entry)> + 'a> {
-		if *self == PageType::Cards {
-			return Box::new(entries.iter_type(EntryType::Card));
+		filter: &'a str,
+	) -> Box<dyn Iterator<Item = (&'a EntryIdentifier, &'a Entry)> + 'a> {
+		let matches = move |e: &Entry| filter.is_empty() || filter_match(filter, &e.name).is_some();
+
+		if let PageType::Log = self {
+			return Box::new(iter::empty());
+		}
+
+		if let UIMode::PickEntries(device) = ui_mode {
+			return Box::new(
+				entries
+					.iter_type(device.picks())
+					.filter(move |(_, e)| matches(e)),
+			);
+		}
+
+		if let PageType::Cards | PageType::Modules = self {
+			let (entry_type, _) = self.parent_child_types();
+
+			return Box::new(
+				entries
+					.iter_type(entry_type)
+					.filter(move |(_, e)| matches(e)),
+			);
 		}
 
 		let (parent, child) = self.parent_child_types();
@@ -103,23 +142,86 @@ impl PageType {
 			);
 		}
 
-		Box::new(
-			entries
-				.iter_type(parent)
-				.map(move |(ident, entry)| {
-					std::iter::once((ident, entry)).chain(entries.iter_type(child).filter(
-						move |(_, e)| {
-							e.parent() == Some(ident.index)
-								&& match &e.entry_kind {
-									EntryKind::CardEntry(_) => true,
-									EntryKind::PlayEntry(play) => {
-										play.hidden != HiddenStatus::Hidden
-									}
-								}
-						},
-					))
-				})
-				.flatten(),
-		)
+		let children = move |ident: &'a EntryIdentifier| {
+			entries.iter_type(child).filter(move |(_, e)| {
+				e.parent() == Some(ident.index)
+					&& match &e.entry_kind {
+						EntryKind::PlayEntry(play) => play.hidden != HiddenStatus::Hidden,
+						_ => true,
+					}
+			})
+		};
+
+		// children of a matching parent are all shown, otherwise only
+		// the matching ones are, together with their parent
+		let mut grouped = HashSet::new();
+		let page = entries
+			.iter_type(parent)
+			.filter(|(ident, entry)| matches(entry) || children(ident).any(|(_, e)| matches(e)))
+			.flat_map(|(ident, entry)| {
+				let parent_matches = matches(entry);
+				let kids = children(ident)
+					.filter(|(_, e)| parent_matches || matches(e))
+					.collect::<Vec<_>>();
+
+				iter::once((ident, entry)).chain(group_by_client(entries, kids, &mut grouped))
+			})
+			.collect::<Vec<_>>();
+
+		Box::new(page.into_iter())
+	}
+}
+
+// streams of a client with more than one of them are listed under the client,
+// after the other streams. a client is grouped only under the first sink or
+// source it has several streams on, as it can't be on the page twice
+fn group_by_client<'a>(
+	entries: &'a Entries,
+	kids: Vec<(&'a EntryIdentifier, &'a Entry)>,
+	grouped: &mut HashSet<u32>,
+) -> Vec<(&'a EntryIdentifier, &'a Entry)> {
+	let client = |e: &Entry| e.entry_kind.play_entry().and_then(|p| p.client);
+
+	let mut clients: Vec<(&EntryIdentifier, &Entry)> = Vec::new();
+	for (_, e) in &kids {
+		let c = match client(e) {
+			Some(c) if !grouped.contains(&c) => c,
+			_ => continue,
+		};
+		if clients.iter().any(|(i, _)| i.index == c)
+			|| kids.iter().filter(|(_, e)| client(e) == Some(c)).count() < 2
+		{
+			continue;
+		}
+		if let Some(client_entry) = entries
+			.iter_type(EntryType::Client)
+			.find(|(i, _)| i.index == c)
+		{
+			clients.push(client_entry);
+		}
+	}
+
+	grouped.extend(clients.iter().map(|(i, _)| i.index));
+
+	let is_grouped = |e: &Entry| match client(e) {
+		Some(c) => clients.iter().any(|(i, _)| i.index == c),
+		None => false,
+	};
+
+	let mut page = kids
+		.iter()
+		.filter(|(_, e)| !is_grouped(e))
+		.copied()
+		.collect::<Vec<_>>();
+
+	for (ident, entry) in &clients {
+		page.push((ident, entry));
+		page.extend(
+			kids.iter()
+				.filter(|(_, e)| client(e) == Some(ident.index))
+				.copied(),
+		);
 	}
+
+	page
 }
@@ -1,30 +1,70 @@
 mod page_entries;
+mod rules;
 
-use std::collections::HashMap;
+use std::{
+	collections::{HashMap, HashSet},
+	time::Instant,
+};
 
 use super::{
-	ContextMenu, ContextMenuEffect, PageEntries, PageType, PulseAudioAction, Redraw, UIMode,
+	inverse, null_sink_module, reroute_from, reroute_to, ContextMenu, ContextMenuEffect, Event,
+	EventKind, EventLog, History, LevelMeter, Levels, PageEntries, PageType, PulseAudioAction,
+	Redraw, ToastExpired, UIMode, VirtualDevice,
 };
 use crate::{
 	actor_system::Ctx,
-	entry::{Entries, Entry, EntryIdentifier, EntryKind},
+	config::{Scene, Scenes},
+	entry::{Entries, Entry, EntryIdentifier, EntryKind, EntryType, HiddenStatus},
+	notifications,
 	ui::{
-		widgets::{HelpWidget, VolumeInputWidget, WarningTextWidget},
+		widgets::{
+			ChannelsWidget, DetailsWidget, FilterWidget, HelpWidget, LogWidget, MessageWidget,
+			ModuleInputWidget, ToastWidget, VolumeInputWidget, WarningTextWidget,
+		},
 		Scrollable, UI,
 	},
-	util::{percent_to_volume, volume_to_percent},
+	unwrap_or_return,
+	util::{percent_to_volume, shift_channel_volume, shift_volume},
+	VARIABLES,
 };
 
 pub struct RSState {
 	pub current_page: PageType,
 	pub entries: Entries,
+	pub defaults: HashSet<EntryIdentifier>,
+	// created after connecting, waiting for their first update
+	pub new_entries: HashSet<EntryIdentifier>,
+	pub hidden_by_rules: HashSet<EntryIdentifier>,
 	pub page_entries: PageEntries,
 	pub context_menu: ContextMenu,
 	pub ui_mode: UIMode,
 	pub redraw: Redraw,
 	pub help: HelpWidget,
 	pub warning_text: WarningTextWidget,
+	pub config_error: Option<String>,
 	pub input_exact_volume: VolumeInputWidget,
+	pub channels: ChannelsWidget,
+	pub details: DetailsWidget,
+	pub filter: FilterWidget,
+	pub message: MessageWidget,
+	pub module_input: ModuleInputWidget,
+	// entries picked for a virtual device
+	pub picked: Vec<EntryIdentifier>,
+	// entries on the current page actions apply to instead of the selected one
+	pub marked: Vec<EntryIdentifier>,
+	// modules loaded by rsmixer, in the order they were loaded
+	pub created_modules: Vec<EntryIdentifier>,
+	// streams moved away from a removed sink or source by fallback routing
+	pub rerouted: HashSet<EntryIdentifier>,
+	// types whose default was moved by fallback routing, same as the streams above
+	pub rerouted_defaults: HashSet<EntryType>,
+	// saved scenes, listed in context menus
+	pub scenes: Scenes,
+	// actions sent to pulseaudio, for undo and redo
+	pub history: History,
+	pub events: EventLog,
+	pub log: LogWidget,
+	pub toast: ToastWidget,
 	pub ui: UI,
 	pub ctx: Option<Ctx>,
 }
@@ -34,6 +74,9 @@ impl Default for RSState {
 		Self {
 			current_page: PageType::Output,
 			entries: Entries::default(),
+			defaults: HashSet::new(),
+			new_entries: HashSet::new(),
+			hidden_by_rules: HashSet::new(),
 			page_entries: PageEntries::new(),
 			context_menu: ContextMenu::default(),
 			ui_mode: UIMode::Normal,
@@ -42,7 +85,23 @@ impl Default for RSState {
 			warning_text: WarningTextWidget {
 				text: "".to_string(),
 			},
+			config_error: None,
 			input_exact_volume: VolumeInputWidget::default(),
+			channels: ChannelsWidget::default(),
+			details: DetailsWidget::default(),
+			filter: FilterWidget::default(),
+			message: MessageWidget::default(),
+			module_input: ModuleInputWidget::default(),
+			picked: Vec::new(),
+			marked: Vec::new(),
+			created_modules: Vec::new(),
+			rerouted: HashSet::new(),
+			rerouted_defaults: HashSet::new(),
+			scenes: Scenes::default(),
+			history: History::default(),
+			events: EventLog::default(),
+			log: LogWidget::default(),
+			toast: ToastWidget::default(),
 			ui: UI::default(),
 			ctx: None,
 		}
@@ -54,15 +113,34 @@ impl RSState {
 		Self {
 			current_page: PageType::Output,
 			entries: Entries::default(),
+			defaults: HashSet::new(),
+			new_entries: HashSet::new(),
+			hidden_by_rules: HashSet::new(),
 			page_entries: PageEntries::new(),
 			context_menu: ContextMenu::default(),
 			ui_mode: UIMode::Normal,
 			redraw: Redraw::default(),
 			help: HelpWidget::default(),
 			input_exact_volume: VolumeInputWidget::default(),
+			channels: ChannelsWidget::default(),
+			details: DetailsWidget::default(),
+			filter: FilterWidget::default(),
+			message: MessageWidget::default(),
+			module_input: ModuleInputWidget::default(),
+			picked: Vec::new(),
+			marked: Vec::new(),
+			created_modules: Vec::new(),
+			rerouted: HashSet::new(),
+			rerouted_defaults: HashSet::new(),
+			scenes: Scenes::default(),
+			history: History::default(),
+			events: EventLog::default(),
+			log: LogWidget::default(),
+			toast: ToastWidget::default(),
 			warning_text: WarningTextWidget {
 				text: "".to_string(),
 			},
+			config_error: None,
 			ui: UI::default(),
 			ctx: Some(ctx),
 		}
@@ -72,7 +150,16 @@ impl RSState {
 			"pulseaudio",
 			PulseAudioAction::CreateMonitors(HashMap::new()),
 		);
-		*self = Self::new(self.ctx.take().unwrap());
+		let mut state = Self::new(self.ctx.take().unwrap());
+		state.scenes = std::mem::take(&mut self.scenes);
+		// the log outlives connections, it's where losing them is seen
+		state.events = std::mem::take(&mut self.events);
+		state.log = std::mem::take(&mut self.log);
+		if self.current_page == PageType::Log {
+			state.current_page = PageType::Log;
+		}
+
+		*self = state;
 		self.redraw.resize = true;
 	}
 	pub fn change_ui_mode(&mut self, mode: UIMode) {
@@ -81,13 +168,34 @@ impl RSState {
 		self.redraw.resize = true;
 	}
 	pub fn remove_entry(&mut self, ident: &EntryIdentifier) {
-		self.entries.remove(ident);
+		if let Some(entry) = self.entries.remove(ident) {
+			self.log_event(Event::new(EventKind::Removed, *ident, &entry.name));
+		}
+		self.new_entries.remove(ident);
+		self.hidden_by_rules.remove(ident);
+		self.picked.retain(|i| i != ident);
+		self.marked.retain(|i| i != ident);
+		self.created_modules.retain(|i| i != ident);
+		self.rerouted.remove(ident);
+
+		let preferred = VARIABLES
+			.read()
+			.unwrap()
+			.fallback_of(ident.entry_type)
+			.to_vec();
+		let actions = reroute_from(&self.entries, &self.defaults, ident, &preferred);
+		self.reroute(actions, true);
+		self.defaults.remove(ident);
 
 		if self.page_entries.ident_position(*ident).is_some() {
 			page_entries::update(self);
 		}
 
-		if self.ui_mode == UIMode::ContextMenu {
+		if let UIMode::ContextMenu | UIMode::Channels = self.ui_mode {
+			self.change_ui_mode(UIMode::Normal);
+		}
+
+		if self.ui_mode == UIMode::Details && self.details.entry_ident == *ident {
 			self.change_ui_mode(UIMode::Normal);
 		}
 	}
@@ -103,19 +211,103 @@ impl RSState {
 			}
 		}
 
+		let created = self.new_entries.remove(ident);
+		if created {
+			rules::apply(self, &entry);
+
+			self.log_event(Event::new(EventKind::Added, *ident, &entry.name));
+		}
+
+		if let Some(play) = entry.entry_kind.play_entry_mut() {
+			play.is_default = self.defaults.contains(ident);
+			play.is_picked = self.picked.contains(ident);
+
+			if self.hidden_by_rules.contains(ident) {
+				play.hidden = HiddenStatus::Hidden;
+			}
+		}
+
 		entry.inherit_area(&self.entries);
 
 		self.entries.insert(*ident, entry);
 
+		if created {
+			let preferred = VARIABLES
+				.read()
+				.unwrap()
+				.fallback_of(ident.entry_type)
+				.to_vec();
+			let actions = reroute_to(
+				&self.entries,
+				&self.defaults,
+				&self.rerouted,
+				&self.rerouted_defaults,
+				ident,
+				&preferred,
+			);
+			self.reroute(actions, false);
+		}
+
+		if self.ui_mode == UIMode::Channels && self.page_entries.get_selected() == Some(*ident) {
+			self.update_channels();
+		}
+
+		if self.ui_mode == UIMode::Details && self.details.entry_ident == *ident {
+			if let Some(entry) = self.entries.get(ident) {
+				self.details.update(entry);
+				self.redraw.resize = true;
+			}
+		}
+
 		page_entries::update(self);
 	}
 
-	pub fn update_peak_volume(&mut self, ident: &EntryIdentifier, peak: &f32) {
+	// sends the moves of fallback routing, remembering the streams and defaults moved away
+	fn reroute(&mut self, actions: Vec<PulseAudioAction>, away: bool) {
+		for action in actions {
+			match &action {
+				PulseAudioAction::MoveEntryToParent(stream, _) if away => {
+					self.rerouted.insert(*stream);
+				}
+				PulseAudioAction::MoveEntryToParent(stream, _) => {
+					self.rerouted.remove(stream);
+				}
+				PulseAudioAction::SetDefault(device, _) if away => {
+					self.rerouted_defaults.insert(device.entry_type);
+				}
+				PulseAudioAction::SetDefault(device, _) => {
+					self.rerouted_defaults.remove(&device.entry_type);
+				}
+				_ => {}
+			}
+
+			self.ctx().send_to("pulseaudio", action);
+		}
+	}
+
+	// logged with the name the entry had before the change
+	pub fn entry_changed(&mut self, ident: &EntryIdentifier) {
+		if let Some(name) = self.entries.get(ident).map(|e| e.name.clone()) {
+			self.log_event(Event::new(EventKind::Changed, *ident, &name));
+		}
+	}
+
+	pub fn update_peak_volume(&mut self, ident: &EntryIdentifier, levels: &[Levels]) {
 		if let Some(play) = self.entries.get_play_entry_mut(ident) {
-			if (play.peak - peak).abs() < f32::EPSILON {
+			// the monitor was reopened with a different number of channels
+			if play.meters.len() != levels.len() {
+				play.meters = vec![LevelMeter::default(); levels.len()];
+			}
+
+			let ballistics = VARIABLES.read().unwrap().meter;
+			let now = Instant::now();
+			let mut changed = false;
+			for (meter, levels) in play.meters.iter_mut().zip(levels) {
+				changed |= meter.update(*levels, now, &ballistics);
+			}
+			if !changed {
 				return;
 			}
-			play.peak = *peak;
 
 			if let Some(i) = self.page_entries.iter_entries().position(|&i| *ident == i) {
 				self.redraw.peak_volume = Some(i);
@@ -123,9 +315,47 @@ impl RSState {
 		}
 	}
 
+	pub fn update_default(&mut self, ident: &EntryIdentifier) {
+		if self.defaults.contains(ident) {
+			return;
+		}
+
+		// not when the first default is learned after connecting
+		if self
+			.defaults
+			.iter()
+			.any(|i| i.entry_type == ident.entry_type)
+		{
+			let name = self
+				.entries
+				.get(ident)
+				.map(|e| e.name.clone())
+				.unwrap_or_default();
+			self.log_event(Event::new(EventKind::DefaultChanged, *ident, &name));
+		}
+
+		self.defaults.retain(|i| i.entry_type != ident.entry_type);
+		self.defaults.insert(*ident);
+
+		for (i, entry) in self.entries.iter_type_mut(ident.entry_type) {
+			if let Some(play) = entry.entry_kind.play_entry_mut() {
+				play.is_default = *i == *ident;
+			}
+		}
+
+		self.redraw.entries = true;
+	}
+
 	pub fn move_down(&mut self, how_much: usize) {
 		match self.ui_mode {
-			UIMode::Normal => {
+			UIMode::Normal | UIMode::Filter if self.current_page == PageType::Log => {
+				if self.log.len() != 0 {
+					self.log.down(how_much);
+				}
+
+				self.redraw.entries = true;
+			}
+			UIMode::Normal | UIMode::Filter | UIMode::PickEntries(_) => {
 				self.selected_entry_needs_redraw();
 				self.page_entries.down(how_much);
 				self.selected_entry_needs_redraw();
@@ -143,6 +373,18 @@ impl RSState {
 
 				self.redraw.context_menu = true;
 			}
+			UIMode::Details => {
+				self.details.down(how_much);
+
+				self.redraw.context_menu = true;
+			}
+			UIMode::Channels => {
+				if !self.channels.channels.is_empty() {
+					self.channels.down(how_much);
+				}
+
+				self.redraw.context_menu = true;
+			}
 			UIMode::MoveEntry(_, _) => {
 				if self.page_entries.entries.len() < 2 {
 					return;
@@ -150,14 +392,14 @@ impl RSState {
 				let l = self.page_entries.len() - 1;
 				let selected = self.page_entries.selected() - 1;
 
-				let mut j = (selected + how_much as usize) % l;
+				let mut j = (selected + how_much) % l;
 
 				if j >= selected {
 					j += 1;
 				}
 
 				let entry_ident = self.page_entries.get_selected().unwrap();
-				let new_parent = self.page_entries.get(j as usize).unwrap();
+				let new_parent = self.page_entries.get(j).unwrap();
 				self.change_ui_mode(UIMode::MoveEntry(entry_ident, new_parent));
 
 				page_entries::update(self);
@@ -168,7 +410,14 @@ impl RSState {
 
 	pub fn move_up(&mut self, how_much: usize) {
 		match self.ui_mode {
-			UIMode::Normal => {
+			UIMode::Normal | UIMode::Filter if self.current_page == PageType::Log => {
+				if self.log.len() != 0 {
+					self.log.up(how_much);
+				}
+
+				self.redraw.entries = true;
+			}
+			UIMode::Normal | UIMode::Filter | UIMode::PickEntries(_) => {
 				self.selected_entry_needs_redraw();
 				self.page_entries.up(how_much);
 				self.selected_entry_needs_redraw();
@@ -186,6 +435,18 @@ impl RSState {
 
 				self.redraw.context_menu = true;
 			}
+			UIMode::Details => {
+				self.details.up(how_much);
+
+				self.redraw.context_menu = true;
+			}
+			UIMode::Channels => {
+				if !self.channels.channels.is_empty() {
+					self.channels.up(how_much);
+				}
+
+				self.redraw.context_menu = true;
+			}
 			UIMode::MoveEntry(_, _) => {
 				if self.page_entries.entries.len() < 2 {
 					return;
@@ -215,7 +476,9 @@ impl RSState {
 	}
 
 	pub fn move_left(&mut self) {
-		if self.context_menu.horizontal_scroll > 0 {
+		if self.ui_mode == UIMode::Normal && self.current_page == PageType::Log {
+			self.change_log_filter(-1);
+		} else if self.context_menu.horizontal_scroll > 0 {
 			self.context_menu.horizontal_scroll -= 1;
 
 			self.redraw.context_menu = true;
@@ -223,16 +486,23 @@ impl RSState {
 	}
 
 	pub fn move_right(&mut self) {
-		if self.context_menu.horizontal_scroll < self.context_menu.max_horizontal_scroll() {
+		if self.ui_mode == UIMode::Normal && self.current_page == PageType::Log {
+			self.change_log_filter(1);
+		} else if self.context_menu.horizontal_scroll < self.context_menu.max_horizontal_scroll() {
 			self.context_menu.horizontal_scroll += 1;
 
 			self.redraw.context_menu = true;
 		}
 	}
 
+	pub fn change_log_filter(&mut self, which_way: i8) {
+		self.log.filter = self.log.filter.cycle(which_way);
+		self.update_log();
+	}
+
 	pub fn set_selected(&mut self, index: usize) {
 		match self.ui_mode {
-			UIMode::Normal => {
+			UIMode::Normal | UIMode::PickEntries(_) => {
 				self.selected_entry_needs_redraw();
 				self.page_entries.set_selected(index);
 				self.selected_entry_needs_redraw();
@@ -249,51 +519,141 @@ impl RSState {
 	}
 
 	pub fn request_mute(&mut self, ident: &Option<EntryIdentifier>) {
-		let ident = match *ident {
-			Some(i) => i,
-			None => match self.page_entries.get_selected() {
-				Some(sel) => sel,
-				None => {
-					return;
-				}
-			},
+		let members = self.targets(ident);
+		// a group is muted unless all of its streams already are
+		let mute = match members
+			.iter()
+			.filter_map(|i| self.entries.get_play_entry(i))
+			.map(|p| p.mute)
+			.reduce(|a, b| a && b)
+		{
+			Some(mute) => mute,
+			None => {
+				return;
+			}
 		};
 
-		let mute = match self.entries.get_play_entry(&ident) {
-			Some(p) => p.mute,
+		for member in members {
+			self.dispatch(PulseAudioAction::MuteEntry(member, !mute));
+		}
+	}
+
+	// streams a client stands for on the current page, any other entry stands for itself
+	pub fn group_members(&self, ident: EntryIdentifier) -> Vec<EntryIdentifier> {
+		let client = match self.entries.get_client_entry(&ident) {
+			Some(client) => client,
 			None => {
-				return;
+				return vec![ident];
 			}
 		};
-		self.ctx()
-			.send_to("pulseaudio", PulseAudioAction::MuteEntry(ident, !mute));
+		let (_, child_type) = self.current_page.parent_child_types();
+
+		self.entries
+			.iter_type(child_type)
+			.filter(|(_, e)| {
+				e.parent() == client.parent
+					&& e.entry_kind.play_entry().and_then(|p| p.client) == Some(ident.index)
+			})
+			.map(|(i, _)| *i)
+			.collect()
 	}
 
-	pub fn request_change_volume(&mut self, how_much: i16, ident: &Option<EntryIdentifier>) {
-		let ident = match *ident {
-			Some(i) => i,
-			None => match self.page_entries.get_selected() {
-				Some(sel) => sel,
-				None => {
-					return;
-				}
-			},
+	// the given entry, otherwise the marked ones or the selected one, with clients standing for their streams
+	pub fn targets(&self, ident: &Option<EntryIdentifier>) -> Vec<EntryIdentifier> {
+		let idents = match *ident {
+			Some(i) => vec![i],
+			None if !self.marked.is_empty() => self.marked.clone(),
+			None => self.page_entries.get_selected().into_iter().collect(),
 		};
 
-		if let Some(play) = self.entries.get_play_entry_mut(&ident) {
-			let mut vols = play.volume;
+		let mut targets = Vec::new();
+		for member in idents.into_iter().flat_map(|i| self.group_members(i)) {
+			if !targets.contains(&member) {
+				targets.push(member);
+			}
+		}
+
+		targets
+	}
+
+	pub fn request_change_volume(&mut self, how_much: i16, ident: &Option<EntryIdentifier>) {
+		for member in self.targets(ident) {
+			if let Some(play) = self.entries.get_play_entry(&member) {
+				let mut vols = play.volume;
+
+				let max_percent = VARIABLES.read().unwrap().max_volume_of(member.entry_type);
+				shift_volume(&mut vols, how_much, max_percent);
+
+				self.dispatch(PulseAudioAction::SetVolume(member, vols));
+			}
+		}
+	}
+
+	pub fn open_channels(&mut self) {
+		let ident = unwrap_or_return!(self.page_entries.get_selected());
+
+		if self.entries.get_play_entry(&ident).is_some() {
+			self.channels.set_selected(0);
+			self.update_channels();
+			self.change_ui_mode(UIMode::Channels);
+		}
+	}
 
-			let target_percent = volume_to_percent(vols) as i16 + how_much;
+	pub fn update_channels(&mut self) {
+		let ident = unwrap_or_return!(self.page_entries.get_selected());
+		let play = unwrap_or_return!(self.entries.get_play_entry(&ident));
 
-			let target = percent_to_volume(target_percent);
+		self.channels.set(
+			play,
+			VARIABLES.read().unwrap().max_volume_of(ident.entry_type),
+		);
 
-			for v in vols.get_mut() {
-				v.0 = target;
+		self.redraw.context_menu = true;
+	}
+
+	pub fn open_details(&mut self) {
+		let ident = unwrap_or_return!(self.page_entries.get_selected());
+
+		if let Some(entry) = self.entries.get(&ident) {
+			if entry.entry_kind.play_entry().is_some() {
+				self.details = DetailsWidget::new(entry);
+				self.change_ui_mode(UIMode::Details);
 			}
+		}
+	}
 
-			self.ctx()
-				.send_to("pulseaudio", PulseAudioAction::SetVolume(ident, vols));
+	pub fn request_change_channel_volume(&mut self, how_much: i16) {
+		let ident = unwrap_or_return!(self.page_entries.get_selected());
+		let play = unwrap_or_return!(self.entries.get_play_entry(&ident));
+
+		let mut vols = play.volume;
+		let max_percent = VARIABLES.read().unwrap().max_volume_of(ident.entry_type);
+
+		if self.channels.locked {
+			shift_volume(&mut vols, how_much, max_percent);
+		} else {
+			let v = unwrap_or_return!(vols.get_mut().get_mut(self.channels.selected()));
+			v.0 = shift_channel_volume(*v, how_much, max_percent);
 		}
+
+		self.dispatch(PulseAudioAction::SetVolume(ident, vols));
+	}
+
+	pub fn request_change_balance(&mut self, how_much: i16) {
+		let ident = unwrap_or_return!(self.page_entries.get_selected());
+		let play = unwrap_or_return!(self.entries.get_play_entry(&ident));
+
+		if !play.channel_map.can_balance() {
+			return;
+		}
+
+		let mut vols = play.volume;
+
+		// balance goes from -1.0 (left) to 1.0 (right)
+		let balance = vols.get_balance(&play.channel_map) + how_much as f32 / 100.0;
+		vols.set_balance(&play.channel_map, balance.clamp(-1.0, 1.0));
+
+		self.dispatch(PulseAudioAction::SetVolume(ident, vols));
 	}
 
 	pub fn setup_volume_input(&mut self) {
@@ -304,25 +664,254 @@ impl RSState {
 			}
 		};
 
-		let percent = if let Some(play) = self.entries.get_play_entry(&ident) {
-			volume_to_percent(play.volume)
-		} else {
-			0
+		let value = match self.entries.get_play_entry(&ident) {
+			Some(play) => VARIABLES
+				.read()
+				.unwrap()
+				.volume_display
+				.format(play.volume.avg()),
+			None => String::new(),
 		};
-		let percent = percent.to_string();
 
-		let cursor = percent.len();
+		let cursor = value.len();
 
-		self.set_volume_input_value(percent, cursor as u8);
+		self.set_volume_input_value(value, cursor as u8);
 	}
 
-	pub fn set_volume_input_value(&mut self, percent: String, cursor: u8) {
+	pub fn set_volume_input_value(&mut self, value: String, cursor: u8) {
 		self.redraw.context_menu = true;
 
-		self.input_exact_volume.value = percent;
+		self.input_exact_volume.value = value;
 		self.input_exact_volume.cursor = cursor;
 	}
 
+	pub fn set_filter_value(&mut self, value: String, cursor: u8) {
+		self.filter.value = value;
+		self.filter.cursor = cursor;
+
+		page_entries::update(self);
+		if self.current_page == PageType::Log {
+			self.update_log();
+		}
+
+		self.redraw.resize = true;
+	}
+
+	pub fn open_module_input(&mut self) {
+		self.module_input.title = "Load module";
+		self.module_input.hint = "e.g. module-null-sink sink_name=virtual";
+		self.set_module_input_value(String::new(), 0);
+		self.change_ui_mode(UIMode::LoadModule);
+	}
+
+	pub fn open_null_sink_input(&mut self) {
+		self.module_input.title = "Create null sink";
+		self.module_input.hint = "name of the new sink";
+		self.set_module_input_value(String::new(), 0);
+		self.change_ui_mode(UIMode::NameNullSink);
+	}
+
+	pub fn set_module_input_value(&mut self, value: String, cursor: u8) {
+		self.module_input.value = value;
+		self.module_input.cursor = cursor;
+
+		self.redraw.context_menu = true;
+	}
+
+	pub fn confirm_load_module(&mut self) {
+		let value = self.module_input.value.trim();
+
+		let (name, argument) = match value.find(char::is_whitespace) {
+			Some(i) => (&value[..i], value[i..].trim_start()),
+			None => (value, ""),
+		};
+
+		if name.is_empty() {
+			return;
+		}
+
+		self.dispatch(PulseAudioAction::LoadModule(
+			name.to_string(),
+			argument.to_string(),
+		));
+	}
+
+	pub fn confirm_null_sink(&mut self) {
+		let (name, argument) = unwrap_or_return!(null_sink_module(&self.module_input.value));
+
+		self.dispatch(PulseAudioAction::LoadModule(name, argument));
+	}
+
+	pub fn start_picking(&mut self, device: VirtualDevice) {
+		// a combined sink always includes the sink it was created from
+		self.picked = match device {
+			VirtualDevice::Combine => self
+				.page_entries
+				.get_selected()
+				.filter(|i| i.entry_type == EntryType::Sink)
+				.into_iter()
+				.collect(),
+			VirtualDevice::Loopback(_) => Vec::new(),
+		};
+		self.update_picked();
+
+		self.change_ui_mode(UIMode::PickEntries(device));
+		page_entries::update(self);
+	}
+
+	pub fn toggle_picked(&mut self) {
+		let ident = unwrap_or_return!(self.page_entries.get_selected());
+
+		match self.picked.iter().position(|i| *i == ident) {
+			Some(i) => {
+				self.picked.remove(i);
+			}
+			None => {
+				self.picked.push(ident);
+			}
+		}
+		self.update_picked();
+
+		self.selected_entry_needs_redraw();
+	}
+
+	pub fn confirm_picking(&mut self, device: VirtualDevice) {
+		if !device.picks_many() {
+			self.picked = self.page_entries.get_selected().into_iter().collect();
+		}
+
+		let module = device.module(&self.picked);
+
+		self.stop_picking();
+
+		match module {
+			Some((name, argument)) => {
+				self.dispatch(PulseAudioAction::LoadModule(name, argument));
+			}
+			None => match device {
+				VirtualDevice::Loopback(_) => self.show_message(
+					"There is no source to loop back from".to_string(),
+					Vec::new(),
+				),
+				VirtualDevice::Combine => {
+					self.show_message("Pick at least two sinks to combine".to_string(), Vec::new())
+				}
+			},
+		}
+	}
+
+	pub fn stop_picking(&mut self) {
+		self.picked.clear();
+		self.update_picked();
+
+		self.change_ui_mode(UIMode::Normal);
+		page_entries::update(self);
+	}
+
+	pub fn toggle_marked(&mut self) {
+		let ident = unwrap_or_return!(self.page_entries.get_selected());
+
+		// entries with a volume and clients standing for their streams
+		if self.entries.get_play_entry(&ident).is_none()
+			&& self.entries.get_client_entry(&ident).is_none()
+		{
+			return;
+		}
+
+		match self.marked.iter().position(|i| *i == ident) {
+			Some(i) => {
+				self.marked.remove(i);
+			}
+			None => {
+				self.marked.push(ident);
+			}
+		}
+
+		self.selected_entry_needs_redraw();
+	}
+
+	pub fn clear_marked(&mut self) {
+		self.marked.clear();
+		self.redraw.entries = true;
+	}
+
+	fn update_picked(&mut self) {
+		for (ident, entry) in self.entries.iter_type_mut(EntryType::Sink) {
+			if let Some(play) = entry.entry_kind.play_entry_mut() {
+				play.is_picked = self.picked.contains(ident);
+			}
+		}
+	}
+
+	pub fn module_loaded(&mut self, ident: &EntryIdentifier) {
+		self.created_modules.push(*ident);
+	}
+
+	pub fn unload_created_modules(&mut self) {
+		// newest first, so devices go away before the ones they were created from
+		for ident in self.created_modules.clone().iter().rev() {
+			self.dispatch(PulseAudioAction::UnloadModule(*ident));
+		}
+	}
+
+	pub fn show_message(&mut self, title: String, lines: Vec<String>) {
+		self.message.title = title;
+		self.message.lines = lines;
+
+		self.change_ui_mode(UIMode::Message);
+	}
+
+	// loaded when starting, when the config is reloaded and when saving a scene
+	pub fn load_scenes(&mut self) {
+		match Scenes::load() {
+			Ok(scenes) => self.scenes = scenes,
+			Err(err) => log::warn!("cannot load scenes: {:#}", err),
+		}
+	}
+
+	pub fn apply_scene(&mut self, name: &str) {
+		let scene = match self.scenes.get(name).cloned() {
+			Some(s) => s,
+			None => {
+				self.show_message(format!("Scene {} doesn't exist", name), Vec::new());
+				return;
+			}
+		};
+
+		let (actions, unmatched) = scene.restore(&self.entries);
+
+		for action in actions {
+			self.dispatch(action);
+		}
+
+		if !unmatched.is_empty() {
+			self.show_message(
+				format!("Scene {} applied, but some entries weren't found:", name),
+				unmatched,
+			);
+		}
+	}
+
+	pub fn save_scene(&mut self, name: &str) {
+		// scenes saved from the command line meanwhile are kept
+		let result = Scenes::load().and_then(|mut scenes| {
+			scenes.insert(name.to_string(), Scene::capture(&self.entries));
+			scenes.store()?;
+
+			Ok(scenes)
+		});
+
+		match result {
+			Ok(scenes) => {
+				self.scenes = scenes;
+				self.show_message(format!("Scene {} saved", name), Vec::new());
+			}
+			Err(err) => {
+				self.show_message("Cannot save scene".to_string(), vec![format!("{:#}", err)])
+			}
+		}
+	}
+
 	pub fn open_context_menu(&mut self, ident: &Option<EntryIdentifier>) {
 		if let Some(ident) = ident {
 			if let Some(index) = self.page_entries.iter_entries().position(|i| *i == *ident) {
@@ -338,7 +927,8 @@ impl RSState {
 				.get(&self.page_entries.get(self.page_entries.selected()).unwrap())
 			{
 				self.ui_mode = UIMode::ContextMenu;
-				self.context_menu = ContextMenu::new(entry);
+				self.context_menu =
+					ContextMenu::new(entry, !self.created_modules.is_empty(), &self.scenes);
 
 				if let EntryKind::CardEntry(card) = &entry.entry_kind {
 					self.context_menu
@@ -351,31 +941,30 @@ impl RSState {
 	}
 
 	pub fn confirm_input_volume(&mut self) {
-		let selected = match self.page_entries.get_selected() {
-			Some(ident) => ident,
+		let display = VARIABLES.read().unwrap().volume_display;
+		// parsed without a maximum, every target gets clamped to its own below
+		let vol = match display.parse(&self.input_exact_volume.value, u16::MAX) {
+			Some(vol) => vol,
 			None => {
+				let text = format!("'{}' is not a volume", self.input_exact_volume.value.trim());
+				self.show_toast(text);
 				return;
 			}
 		};
 
-		let percent = match self.input_exact_volume.value.parse::<u16>() {
-			Ok(percent) => percent,
-			Err(_) => {
-				return;
-			}
-		};
+		for target in self.targets(&None) {
+			let max_percent = VARIABLES.read().unwrap().max_volume_of(target.entry_type);
+			let vol = vol.min(percent_to_volume(i16::MAX, max_percent));
 
-		let vol = percent_to_volume(percent as i16);
+			if let Some(play) = self.entries.get_play_entry(&target) {
+				let mut vols = play.volume;
 
-		if let Some(play) = self.entries.get_play_entry_mut(&selected) {
-			let mut vols = play.volume;
+				for v in vols.get_mut() {
+					v.0 = vol;
+				}
 
-			for v in vols.get_mut() {
-				v.0 = vol;
+				self.dispatch(PulseAudioAction::SetVolume(target, vols));
 			}
-
-			self.ctx()
-				.send_to("pulseaudio", PulseAudioAction::SetVolume(selected, vols));
 		}
 	}
 
@@ -387,17 +976,39 @@ impl RSState {
 			}
 		};
 
-		let answer = self.context_menu.resolve(selected, &self.ctx());
+		let answer = self.context_menu.resolve(selected, self.ctx());
 
 		match answer {
 			ContextMenuEffect::None => {
 				self.change_ui_mode(UIMode::Normal);
 			}
+			ContextMenuEffect::Dispatch(action) => {
+				self.change_ui_mode(UIMode::Normal);
+				self.dispatch(action);
+			}
+			ContextMenuEffect::ChangePort => match self.entries.get(&selected) {
+				Some(entry) => {
+					self.context_menu = ContextMenu::ports(entry);
+					self.redraw.resize = true;
+				}
+				None => {
+					self.change_ui_mode(UIMode::Normal);
+				}
+			},
+			ContextMenuEffect::Kill => {
+				self.change_ui_mode(UIMode::Normal);
+
+				for target in self.targets(&None) {
+					if target.entry_type == EntryType::SinkInput {
+						self.dispatch(PulseAudioAction::KillEntry(target));
+					}
+				}
+			}
 			ContextMenuEffect::MoveEntry => {
 				let (parent_type, _) = self.current_page.parent_child_types();
 				let entry_ident = selected;
 
-				if let Some(parent_id) = self.entries.get_play_entry(&entry_ident).unwrap().parent {
+				if let Some(parent_id) = self.entries.get(&entry_ident).and_then(|e| e.parent()) {
 					let entry_parent = EntryIdentifier::new(parent_type, parent_id);
 					let parent_ident = match self.entries.find(|(&i, _)| i == entry_parent) {
 						Some((i, _)) => *i,
@@ -426,14 +1037,111 @@ impl RSState {
 		};
 
 		self.entries.hide(ident);
+		self.marked.retain(|i| *i != ident);
 
 		page_entries::update(self);
 	}
 
 	pub fn change_page(&mut self, page: PageType) {
 		self.current_page = page;
+		self.marked.clear();
 		self.change_ui_mode(UIMode::Normal);
 		page_entries::update(self);
+
+		if page == PageType::Log {
+			self.update_log();
+		}
+	}
+
+	// sends an action to pulseaudio, remembering how to undo it
+	pub fn dispatch(&mut self, action: PulseAudioAction) {
+		// moved by hand, fallback routing leaves it there
+		match &action {
+			PulseAudioAction::MoveEntryToParent(stream, _) => {
+				self.rerouted.remove(stream);
+			}
+			PulseAudioAction::SetDefault(device, _) => {
+				self.rerouted_defaults.remove(&device.entry_type);
+			}
+			_ => {}
+		}
+
+		if let Some(inverse) = inverse(&action, &self.entries, &self.defaults) {
+			self.history.record(action.clone(), inverse);
+		}
+
+		self.ctx().send_to("pulseaudio", action);
+	}
+
+	pub fn undo(&mut self) {
+		for action in unwrap_or_return!(self.history.undo()) {
+			self.ctx().send_to("pulseaudio", action);
+		}
+	}
+
+	pub fn redo(&mut self) {
+		for action in unwrap_or_return!(self.history.redo()) {
+			self.ctx().send_to("pulseaudio", action);
+		}
+	}
+
+	// keeps the event for the log page, the ones about devices are also announced
+	pub fn log_event(&mut self, event: Event) {
+		if matches!(event.ident, Some(i) if Event::is_device(&i))
+			&& event.kind != EventKind::Changed
+		{
+			self.announce(event.text());
+		}
+
+		let max = VARIABLES.read().unwrap().log_size;
+		self.events.push(event, max);
+
+		if self.current_page == PageType::Log {
+			self.update_log();
+		}
+	}
+
+	fn update_log(&mut self) {
+		self.log.update(&self.events, &self.filter.value);
+		self.redraw.entries = true;
+	}
+
+	// shows the text as a toast and sends it as a desktop notification
+	fn announce(&mut self, text: String) {
+		let variables = VARIABLES.read().unwrap();
+		let toasts = variables.toasts;
+		if variables.notifications {
+			notifications::send(text.clone());
+		}
+		drop(variables);
+
+		if toasts {
+			self.show_toast(text);
+		}
+	}
+
+	// errors are shown even with toasts of events turned off
+	fn show_toast(&mut self, text: String) {
+		let duration = VARIABLES.read().unwrap().toast_duration;
+
+		self.toast.id += 1;
+		let id = self.toast.id;
+		self.toast.text = text;
+		self.redraw.resize = true;
+
+		let ctx = self.ctx().clone();
+		tokio::spawn(async move {
+			tokio::time::sleep(duration).await;
+			ctx.send_to("event_loop", ToastExpired(id));
+		});
+	}
+
+	// a newer event keeps its toast
+	pub fn hide_toast(&mut self, id: u64) {
+		if id == self.toast.id && !self.toast.text.is_empty() {
+			self.toast.text.clear();
+			self.redraw.resize = true;
+		}
 	}
 
 	pub fn ctx(&self) -> &Ctx {
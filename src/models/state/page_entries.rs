@@ -2,8 +2,8 @@ use std::collections::{HashMap, HashSet};
 
 use super::RSState;
 use crate::{
-	entry::{EntryIdentifier, EntryKind, EntryType, HiddenStatus},
-	models::{PageType, PulseAudioAction, UIMode},
+	entry::{EntryIdentifier, EntryKind, EntrySpaceLvl, EntryType, HiddenStatus},
+	models::{MonitorTarget, PulseAudioAction, UIMode},
 	ui::Scrollable,
 };
 
@@ -12,7 +12,7 @@ pub fn update(state: &mut RSState) {
 
 	let (p, c) = state.current_page.parent_child_types();
 
-	if p != EntryType::Card && c != EntryType::Card {
+	if state.current_page.has_volume() {
 		let mut parents = HashSet::new();
 		state.entries.iter_type(c).for_each(|(_, e)| {
 			if let EntryKind::PlayEntry(play) = &e.entry_kind {
@@ -30,15 +30,23 @@ pub fn update(state: &mut RSState) {
 		}
 	}
 
+	// entries being picked are listed without their children
+	let lvl_parent = match state.ui_mode {
+		UIMode::PickEntries(device) => device.picks(),
+		_ => p,
+	};
+
 	let entries_changed = state.page_entries.set(
 		state
 			.current_page
-			.generate_page(&state.entries, &state.ui_mode)
+			.generate_page(&state.entries, &state.ui_mode, &state.filter.value)
 			.map(|x| *x.0)
 			.collect::<Vec<EntryIdentifier>>(),
-		p,
+		lvl_parent,
 	);
 
+	update_clients(state);
+
 	match state.ui_mode {
 		UIMode::MoveEntry(ident, _) => {
 			if let Some(i) = state.page_entries.iter_entries().position(|&x| x == ident) {
@@ -52,6 +60,10 @@ pub fn update(state: &mut RSState) {
 				.position(|&x| Some(x) == last_sel)
 			{
 				state.page_entries.set_selected(i);
+			} else if state.page_entries.selected() >= state.page_entries.len() {
+				// selected entry was removed from the end of the page
+				let last = state.page_entries.len().saturating_sub(1);
+				state.page_entries.set_selected(last);
 			}
 		}
 	};
@@ -66,19 +78,74 @@ pub fn update(state: &mut RSState) {
 	}
 }
 
-fn monitor_list(state: &mut RSState) -> HashMap<EntryIdentifier, Option<u32>> {
+// clients on the page stand for the streams listed below them
+fn update_clients(state: &mut RSState) {
+	let mut groups = Vec::new();
+	let mut parent = None;
+	let mut client = None;
+
+	for (i, ident) in state.page_entries.iter_entries().enumerate() {
+		match state.page_entries.lvls[i] {
+			EntrySpaceLvl::Parent | EntrySpaceLvl::ParentNoChildren => {
+				parent = Some(ident.index);
+			}
+			_ if ident.entry_type == EntryType::Client => {
+				groups.push((*ident, parent, Vec::new(), false));
+				client = Some(groups.len() - 1);
+			}
+			EntrySpaceLvl::ClientChild {
+				parent_continues, ..
+			} => {
+				if let Some(c) = client {
+					groups[c].2.push(*ident);
+					groups[c].3 = !parent_continues;
+				}
+			}
+			_ => {}
+		}
+	}
+
+	for (ident, parent, streams, last) in groups {
+		let mute = !streams.is_empty()
+			&& streams
+				.iter()
+				.all(|s| state.entries.get_play_entry(s).is_some_and(|p| p.mute));
+
+		if let Some(client) = state
+			.entries
+			.get_mut(&ident)
+			.and_then(|e| e.entry_kind.client_entry_mut())
+		{
+			// a client being moved is listed alone, it keeps its group's parent
+			if !streams.is_empty() {
+				client.parent = parent;
+			}
+			client.streams = streams.len();
+			client.last = last;
+			client.mute = mute;
+		}
+	}
+}
+
+fn monitor_list(state: &mut RSState) -> HashMap<EntryIdentifier, MonitorTarget> {
 	let mut monitors = HashMap::new();
 
-	if state.current_page == PageType::Cards {
+	if !state.current_page.has_volume() {
 		return monitors;
 	}
 
 	state.page_entries.iter_entries().for_each(|ident| {
+		// clients don't play anything themselves
 		if let Some(entry) = state.entries.get(ident) {
-			monitors.insert(
-				EntryIdentifier::new(entry.entry_type, entry.index),
-				entry.monitor_source(&state.entries),
-			);
+			if let Some(play) = entry.entry_kind.play_entry() {
+				monitors.insert(
+					EntryIdentifier::new(entry.entry_type, entry.index),
+					MonitorTarget {
+						source: entry.monitor_source(&state.entries),
+						channel_map: play.channel_map,
+					},
+				);
+			}
 		}
 	});
 
@@ -0,0 +1,32 @@
+use super::RSState;
+use crate::{
+	entry::{Entry, EntryType},
+	unwrap_or_return, VARIABLES,
+};
+
+pub fn apply(state: &mut RSState, entry: &Entry) {
+	let play = unwrap_or_return!(entry.entry_kind.play_entry());
+	if !matches!(
+		entry.entry_type,
+		EntryType::SinkInput | EntryType::SourceOutput
+	) {
+		return;
+	}
+
+	let variables = VARIABLES.read().unwrap();
+	let rule = unwrap_or_return!(variables
+		.rules
+		.iter()
+		.find(|r| r.matches(&entry.name, &play.properties)));
+
+	log::debug!("rule {:?} matches {}", rule, entry.name);
+
+	let max_percent = variables.max_volume_of(entry.entry_type);
+	for action in rule.actions(entry, &state.entries, max_percent) {
+		state.ctx().send_to("pulseaudio", action);
+	}
+
+	if rule.hidden == Some(true) {
+		state.hidden_by_rules.insert(entry.entry_ident);
+	}
+}
@@ -7,6 +7,8 @@ pub enum Style {
 	Red,
 	Green,
 	Orange,
+	// volume above 100%
+	Warning,
 }
 impl Eq for Style {}
 
@@ -20,6 +22,7 @@ impl From<&String> for Style {
 			"red" => Style::Red,
 			"green" => Style::Green,
 			"orange" => Style::Orange,
+			"warning" => Style::Warning,
 			_ => Style::Normal,
 		}
 	}
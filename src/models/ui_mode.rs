@@ -1,11 +1,19 @@
+use super::VirtualDevice;
 use crate::entry::EntryIdentifier;
 
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Clone, Debug)]
 pub enum UIMode {
 	Normal,
 	ContextMenu,
 	Help,
 	MoveEntry(EntryIdentifier, EntryIdentifier),
 	InputVolumeValue,
+	Channels,
+	Details,
+	Filter,
+	Message,
+	LoadModule,
+	NameNullSink,
+	PickEntries(VirtualDevice),
 	RetryIn(u64),
 }
@@ -0,0 +1,85 @@
+use crate::entry::{EntryIdentifier, EntryType};
+
+// devices created from the output page by loading a module,
+// pulseaudio resolves a numeric device name as its index
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum VirtualDevice {
+	// loopback from a picked source to the sink
+	Loopback(EntryIdentifier),
+	// combine-sink playing to all picked sinks
+	Combine,
+}
+
+impl VirtualDevice {
+	// type of the entries shown while picking
+	pub fn picks(&self) -> EntryType {
+		match self {
+			Self::Loopback(_) => EntryType::Source,
+			Self::Combine => EntryType::Sink,
+		}
+	}
+
+	pub fn picks_many(&self) -> bool {
+		matches!(self, Self::Combine)
+	}
+
+	// shown over the bottom border while picking
+	pub fn hint(&self) -> &'static str {
+		match self {
+			Self::Loopback(_) => " enter loops back the selected source ",
+			Self::Combine => " space picks sinks, enter combines them ",
+		}
+	}
+
+	// module name and arguments, None when not enough entries were picked
+	pub fn module(&self, picked: &[EntryIdentifier]) -> Option<(String, String)> {
+		match self {
+			Self::Loopback(sink) => picked.first().map(|source| {
+				(
+					"module-loopback".to_string(),
+					format!("source={} sink={}", source.index, sink.index),
+				)
+			}),
+			Self::Combine if picked.len() > 1 => Some((
+				"module-combine-sink".to_string(),
+				format!(
+					"slaves={}",
+					picked
+						.iter()
+						.map(|i| i.index.to_string())
+						.collect::<Vec<_>>()
+						.join(",")
+				),
+			)),
+			Self::Combine => None,
+		}
+	}
+}
+
+// module name and arguments of a null sink, the name is also used as its description
+pub fn null_sink_module(name: &str) -> Option<(String, String)> {
+	let name = name.trim();
+	if name.is_empty() {
+		return None;
+	}
+
+	let sink_name = name
+		.chars()
+		.map(|c| {
+			if c.is_ascii_alphanumeric() || c == '-' || c == '.' {
+				c
+			} else {
+				'_'
+			}
+		})
+		.collect::<String>();
+	let description = name.replace(['\'', '"'], "");
+
+	Some((
+		"module-null-sink".to_string(),
+		format!(
+			"sink_name={} sink_properties='device.description=\"{}\"'",
+			sink_name, description
+		),
+	))
+}
@@ -0,0 +1,60 @@
+// desktop notifications sent to org.freedesktop.Notifications on the session bus,
+// only available when built with the notifications feature
+
+#[cfg(feature = "notifications")]
+use std::{collections::HashMap, time::Duration};
+
+#[cfg(feature = "notifications")]
+use dbus::{
+	arg::{RefArg, Variant},
+	blocking::Connection,
+};
+
+#[cfg(feature = "notifications")]
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+pub fn available() -> bool {
+	cfg!(feature = "notifications")
+}
+
+// doesn't wait for the server, the event loop shouldn't block on it
+#[cfg(feature = "notifications")]
+pub fn send(summary: String) {
+	std::thread::spawn(move || {
+		if let Err(err) = notify(&summary) {
+			log::warn!("cannot send a notification: {}", err);
+		}
+	});
+}
+
+#[cfg(not(feature = "notifications"))]
+pub fn send(_summary: String) {}
+
+// returns the id the server gave to the notification
+#[cfg(feature = "notifications")]
+pub fn notify(summary: &str) -> Result<u32, dbus::Error> {
+	let connection = Connection::new_session()?;
+	let proxy = connection.with_proxy(
+		"org.freedesktop.Notifications",
+		"/org/freedesktop/Notifications",
+		TIMEOUT,
+	);
+
+	let hints: HashMap<&str, Variant<Box<dyn RefArg>>> = HashMap::new();
+	let (id,): (u32,) = proxy.method_call(
+		"org.freedesktop.Notifications",
+		"Notify",
+		(
+			"rsmixer",
+			0u32,
+			"audio-card",
+			summary,
+			"",
+			Vec::<&str>::new(),
+			hints,
+			-1i32,
+		),
+	)?;
+
+	Ok(id)
+}
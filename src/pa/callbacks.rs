@@ -1,15 +1,22 @@
+use std::{borrow::Cow, collections::BTreeMap, rc::Weak};
+
 use pulse::{
 	callbacks::ListResult,
 	context::{
-		introspect::{CardInfo, SinkInfo, SinkInputInfo, SourceInfo, SourceOutputInfo},
+		introspect::{
+			CardInfo, ClientInfo, ModuleInfo, ServerInfo, SinkInfo, SinkInputInfo, SourceInfo,
+			SourceOutputInfo,
+		},
 		subscribe::{InterestMaskSet, Operation},
 	},
-	def::{SinkState, SourceState},
+	def::{PortAvailable, SinkState, SourceState},
+	proplist::Proplist,
+	time::MicroSeconds,
 };
 
 use super::{common::*, pa_interface::ACTIONS_SX};
 use crate::{
-	entry::{CardProfile, Entry},
+	entry::{CardProfile, Entry, Port},
 	models::EntryUpdate,
 	ui::Rect,
 };
@@ -20,12 +27,15 @@ pub fn subscribe(
 ) -> Result<()> {
 	info!("[PAInterface] Registering pulseaudio callbacks");
 
+	let context_ref = Rc::downgrade(context);
+
 	context.borrow_mut().subscribe(
 		InterestMaskSet::SINK
 			| InterestMaskSet::SINK_INPUT
 			| InterestMaskSet::SOURCE
 			| InterestMaskSet::CARD
 			| InterestMaskSet::SOURCE_OUTPUT
+			| InterestMaskSet::MODULE
 			| InterestMaskSet::CLIENT
 			| InterestMaskSet::SERVER,
 		|success: bool| {
@@ -36,25 +46,33 @@ pub fn subscribe(
 	context.borrow_mut().set_subscribe_callback(Some(Box::new(
 		move |facility, operation, index| {
 			if let Some(facility) = facility {
-				match facility {
-					Facility::Server | Facility::Client => {
-						log::error!("{:?} {:?}", facility, operation);
-						return;
-					}
-					_ => {}
-				};
+				if facility == Facility::Server {
+					info!("[PAInterface] Server changed");
+					let introspector = unsafe { (*(*context_ref.as_ptr()).as_ptr()).introspect() };
+					introspector.get_server_info(on_server_info(context_ref.clone()));
+					return;
+				}
 
 				let entry_type: EntryType = facility.into();
 				match operation {
 					Some(Operation::New) => {
 						info!("[PAInterface] New {:?}", entry_type);
 
+						let _ = (*ACTIONS_SX).get().send(EntryUpdate::EntryCreated(
+							EntryIdentifier::new(entry_type, index),
+						));
+
 						info_sx
 							.send(EntryIdentifier::new(entry_type, index))
 							.unwrap();
 					}
 					Some(Operation::Changed) => {
 						info!("[PAInterface] {:?} changed", entry_type);
+
+						let _ = (*ACTIONS_SX).get().send(EntryUpdate::EntryChanged(
+							EntryIdentifier::new(entry_type, index),
+						));
+
 						info_sx
 							.send(EntryIdentifier::new(entry_type, index))
 							.unwrap();
@@ -79,49 +97,31 @@ pub fn subscribe(
 
 pub fn request_current_state(
 	context: Rc<RefCell<PAContext>>,
-	info_sxx: mpsc::UnboundedSender<EntryIdentifier>,
+	info_sx: mpsc::UnboundedSender<EntryIdentifier>,
 ) -> Result<()> {
 	info!("[PAInterface] Requesting starting state");
 
 	let introspector = context.borrow_mut().introspect();
 
-	let info_sx = info_sxx.clone();
-	introspector.get_sink_info_list(move |x: ListResult<&SinkInfo>| {
-		if let ListResult::Item(e) = x {
-			let _ = info_sx
-				.clone()
-				.send(EntryIdentifier::new(EntryType::Sink, e.index));
-		}
-	});
-
-	let info_sx = info_sxx.clone();
-	introspector.get_sink_input_info_list(move |x: ListResult<&SinkInputInfo>| {
-		if let ListResult::Item(e) = x {
-			let _ = info_sx.send(EntryIdentifier::new(EntryType::SinkInput, e.index));
-		}
-	});
+	introspector.get_sink_info_list(on_sink_info(&info_sx));
+	introspector.get_sink_input_info_list(on_sink_input_info(&info_sx));
+	introspector.get_source_info_list(on_source_info(&info_sx));
+	introspector.get_source_output_info_list(on_source_output_info(&info_sx));
+	introspector.get_card_info_list(on_card_info);
+	introspector.get_module_info_list(on_module_info);
+	introspector.get_client_info_list(on_client_info);
 
-	let info_sx = info_sxx.clone();
-	introspector.get_source_info_list(move |x: ListResult<&SourceInfo>| {
-		if let ListResult::Item(e) = x {
-			let _ = info_sx.send(EntryIdentifier::new(EntryType::Source, e.index));
-		}
-	});
+	introspector.get_server_info(on_server_info(Rc::downgrade(&context)));
 
-	let info_sx = info_sxx.clone();
-	introspector.get_source_output_info_list(move |x: ListResult<&SourceOutputInfo>| {
-		if let ListResult::Item(e) = x {
-			let _ = info_sx.send(EntryIdentifier::new(EntryType::SourceOutput, e.index));
-		}
-	});
+	Ok(())
+}
 
-	introspector.get_card_info_list(move |x: ListResult<&CardInfo>| {
-		if let ListResult::Item(e) = x {
-			let _ = info_sxx.send(EntryIdentifier::new(EntryType::Card, e.index));
-		}
+pub fn sync(context: &Rc<RefCell<PAContext>>) {
+	// the server answers requests in order, so by the time this one
+	// comes back every request sent before it has been answered too
+	context.borrow_mut().introspect().get_server_info(|_| {
+		let _ = (*ACTIONS_SX).get().send(EntryUpdate::Synced);
 	});
-
-	Ok(())
 }
 
 pub fn request_info(
@@ -150,8 +150,39 @@ pub fn request_info(
 		EntryType::Card => {
 			introspector.get_card_info_by_index(ident.index, on_card_info);
 		}
+		EntryType::Module => {
+			introspector.get_module_info(ident.index, on_module_info);
+		}
+		EntryType::Client => {
+			introspector.get_client_info(ident.index, on_client_info);
+		}
 	};
 }
+
+pub fn on_server_info(context_ref: Weak<RefCell<PAContext>>) -> impl FnMut(&ServerInfo) {
+	move |i: &ServerInfo| {
+		debug!("[PADataInterface] Update server info");
+		let introspector = unsafe { (*(*context_ref.as_ptr()).as_ptr()).introspect() };
+
+		if let Some(name) = &i.default_sink_name {
+			introspector.get_sink_info_by_name(name, |res: ListResult<&SinkInfo>| {
+				if let ListResult::Item(i) = res {
+					let ident = EntryIdentifier::new(EntryType::Sink, i.index);
+					let _ = (*ACTIONS_SX).get().send(EntryUpdate::DefaultChanged(ident));
+				}
+			});
+		}
+		if let Some(name) = &i.default_source_name {
+			introspector.get_source_info_by_name(name, |res: ListResult<&SourceInfo>| {
+				if let ListResult::Item(i) = res {
+					let ident = EntryIdentifier::new(EntryType::Source, i.index);
+					let _ = (*ACTIONS_SX).get().send(EntryUpdate::DefaultChanged(ident));
+				}
+			});
+		}
+	}
+}
+
 pub fn on_card_info(res: ListResult<&CardInfo>) {
 	if let ListResult::Item(i) = res {
 		let n = match i
@@ -161,7 +192,7 @@ pub fn on_card_info(res: ListResult<&CardInfo>) {
 			Some(s) => s,
 			None => String::from(""),
 		};
-		let profiles: Vec<CardProfile> = i
+		let mut profiles: Vec<CardProfile> = i
 			.profiles
 			.iter()
 			.filter_map(|p| {
@@ -173,11 +204,21 @@ pub fn on_card_info(res: ListResult<&CardInfo>) {
 						Some(s) => s.to_string(),
 						None => n.to_string(),
 					},
+					n_sinks: p.n_sinks,
+					n_sources: p.n_sources,
+					priority: p.priority,
 					#[cfg(any(feature = "pa_v13"))]
 					available: p.available,
 				})
 			})
 			.collect();
+		// the most useful profiles first
+		profiles.sort_by_key(|p| std::cmp::Reverse(p.priority));
+		let ports = i
+			.ports
+			.iter()
+			.filter_map(|p| port(&p.name, &p.description, p.available))
+			.collect();
 
 		let selected_profile = match &i.active_profile {
 			Some(x) => {
@@ -191,7 +232,61 @@ pub fn on_card_info(res: ListResult<&CardInfo>) {
 		};
 
 		let ident = EntryIdentifier::new(EntryType::Card, i.index);
-		let entry = Entry::new_card_entry(i.index, n, profiles, selected_profile);
+		let entry =
+			Entry::new_card_entry(i.index, n, profiles, selected_profile).ports(ports, None);
+
+		(*ACTIONS_SX)
+			.get()
+			.send(EntryUpdate::EntryUpdate(ident, Box::new(entry)))
+			.unwrap();
+	}
+}
+
+pub fn on_module_info(res: ListResult<&ModuleInfo>) {
+	if let ListResult::Item(i) = res {
+		debug!("[PADataInterface] Update {} module info", i.index);
+		let name = match &i.name {
+			Some(name) => name.to_string(),
+			None => String::new(),
+		};
+		let argument = match &i.argument {
+			Some(argument) => argument.to_string(),
+			None => String::new(),
+		};
+
+		let ident = EntryIdentifier::new(EntryType::Module, i.index);
+		let entry = Entry::new_module_entry(i.index, name, argument);
+
+		(*ACTIONS_SX)
+			.get()
+			.send(EntryUpdate::EntryUpdate(ident, Box::new(entry)))
+			.unwrap();
+	}
+}
+
+pub fn on_client_info(res: ListResult<&ClientInfo>) {
+	if let ListResult::Item(i) = res {
+		debug!("[PADataInterface] Update {} client info", i.index);
+		let name = match i
+			.proplist
+			.get_str(pulse::proplist::properties::APPLICATION_NAME)
+		{
+			Some(s) => s,
+			None => match &i.name {
+				Some(s) => s.to_string(),
+				None => String::new(),
+			},
+		};
+		let binary = i
+			.proplist
+			.get_str(pulse::proplist::properties::APPLICATION_PROCESS_BINARY);
+		let pid = i
+			.proplist
+			.get_str(pulse::proplist::properties::APPLICATION_PROCESS_ID)
+			.and_then(|pid| pid.parse().ok());
+
+		let ident = EntryIdentifier::new(EntryType::Client, i.index);
+		let entry = Entry::new_client_entry(i.index, name, binary, pid);
 
 		(*ACTIONS_SX)
 			.get()
@@ -218,10 +313,31 @@ pub fn on_sink_info(
 				None,
 				i.mute,
 				i.volume,
+				i.channel_map,
 				Some(i.monitor_source),
 				None,
 				i.state == SinkState::Suspended,
 			);
+			let ports = i
+				.ports
+				.iter()
+				.filter_map(|p| port(&p.name, &p.description, p.available))
+				.collect::<Vec<Port>>();
+			let active_port = active_port(&ports, i.active_port.as_ref().map(|p| &p.name));
+			let entry = entry
+				.ports(ports, active_port)
+				.device_name(i.name.as_ref().map(|n| n.to_string()).unwrap_or_default())
+				.properties(properties(&i.proplist))
+				.details(details(vec![
+					("Name", i.name.as_ref().map(|n| n.to_string())),
+					("Driver", i.driver.as_ref().map(|d| d.to_string())),
+					("Owner module", i.owner_module.map(|m| m.to_string())),
+					("Card", i.card.map(|c| c.to_string())),
+					("Sample spec", Some(i.sample_spec.print())),
+					("Channel map", Some(i.channel_map.print())),
+					("Latency", Some(latency(i.latency))),
+					("Configured latency", Some(latency(i.configured_latency))),
+				]));
 
 			(*ACTIONS_SX)
 				.get()
@@ -257,10 +373,37 @@ pub fn on_sink_input_info(
 				Some(i.sink),
 				i.mute,
 				i.volume,
+				i.channel_map,
 				None,
 				Some(i.sink),
 				false,
-			);
+			)
+			.client(i.client)
+			.properties(properties(&i.proplist))
+			.details(details(vec![
+				("Name", i.name.as_ref().map(|n| n.to_string())),
+				("Driver", i.driver.as_ref().map(|d| d.to_string())),
+				("Owner module", i.owner_module.map(|m| m.to_string())),
+				("Client", i.client.map(|c| c.to_string())),
+				(
+					"Client PID",
+					i.proplist
+						.get_str(pulse::proplist::properties::APPLICATION_PROCESS_ID),
+				),
+				(
+					"Client binary",
+					i.proplist
+						.get_str(pulse::proplist::properties::APPLICATION_PROCESS_BINARY),
+				),
+				("Sample spec", Some(i.sample_spec.print())),
+				("Channel map", Some(i.channel_map.print())),
+				("Buffer latency", Some(latency(i.buffer_usec))),
+				("Sink latency", Some(latency(i.sink_usec))),
+				(
+					"Resample method",
+					i.resample_method.as_ref().map(|r| r.to_string()),
+				),
+			]));
 
 			(*ACTIONS_SX)
 				.get()
@@ -289,10 +432,31 @@ pub fn on_source_info(
 				None,
 				i.mute,
 				i.volume,
+				i.channel_map,
 				Some(i.index),
 				None,
 				i.state == SourceState::Suspended,
 			);
+			let ports = i
+				.ports
+				.iter()
+				.filter_map(|p| port(&p.name, &p.description, p.available))
+				.collect::<Vec<Port>>();
+			let active_port = active_port(&ports, i.active_port.as_ref().map(|p| &p.name));
+			let entry = entry
+				.ports(ports, active_port)
+				.device_name(i.name.as_ref().map(|n| n.to_string()).unwrap_or_default())
+				.properties(properties(&i.proplist))
+				.details(details(vec![
+					("Name", i.name.as_ref().map(|n| n.to_string())),
+					("Driver", i.driver.as_ref().map(|d| d.to_string())),
+					("Owner module", i.owner_module.map(|m| m.to_string())),
+					("Card", i.card.map(|c| c.to_string())),
+					("Sample spec", Some(i.sample_spec.print())),
+					("Channel map", Some(i.channel_map.print())),
+					("Latency", Some(latency(i.latency))),
+					("Configured latency", Some(latency(i.configured_latency))),
+				]));
 
 			(*ACTIONS_SX)
 				.get()
@@ -327,10 +491,37 @@ pub fn on_source_output_info(
 				Some(i.source),
 				i.mute,
 				i.volume,
+				i.channel_map,
 				Some(i.source),
 				None,
 				false,
-			);
+			)
+			.client(i.client)
+			.properties(properties(&i.proplist))
+			.details(details(vec![
+				("Name", i.name.as_ref().map(|n| n.to_string())),
+				("Driver", i.driver.as_ref().map(|d| d.to_string())),
+				("Owner module", i.owner_module.map(|m| m.to_string())),
+				("Client", i.client.map(|c| c.to_string())),
+				(
+					"Client PID",
+					i.proplist
+						.get_str(pulse::proplist::properties::APPLICATION_PROCESS_ID),
+				),
+				(
+					"Client binary",
+					i.proplist
+						.get_str(pulse::proplist::properties::APPLICATION_PROCESS_BINARY),
+				),
+				("Sample spec", Some(i.sample_spec.print())),
+				("Channel map", Some(i.channel_map.print())),
+				("Buffer latency", Some(latency(i.buffer_usec))),
+				("Source latency", Some(latency(i.source_usec))),
+				(
+					"Resample method",
+					i.resample_method.as_ref().map(|r| r.to_string()),
+				),
+			]));
 
 			(*ACTIONS_SX)
 				.get()
@@ -340,3 +531,48 @@ pub fn on_source_output_info(
 		}
 	}
 }
+
+fn properties(proplist: &Proplist) -> BTreeMap<String, String> {
+	proplist
+		.iter()
+		.filter_map(|key| {
+			let value = proplist.get_str(&key)?;
+			Some((key, value))
+		})
+		.collect()
+}
+
+// drops details pulseaudio didn't report
+fn details(details: Vec<(&str, Option<String>)>) -> Vec<(String, String)> {
+	details
+		.into_iter()
+		.filter_map(|(name, value)| Some((name.to_string(), value?)))
+		.collect()
+}
+
+fn latency(usec: MicroSeconds) -> String {
+	format!("{:.1} ms", usec.0 as f64 / 1000.0)
+}
+
+fn port(
+	name: &Option<Cow<str>>,
+	description: &Option<Cow<str>>,
+	available: PortAvailable,
+) -> Option<Port> {
+	let name = name.as_ref()?.to_string();
+
+	Some(Port {
+		description: match description {
+			Some(d) => d.to_string(),
+			None => name.clone(),
+		},
+		name,
+		available: available != PortAvailable::No,
+	})
+}
+
+fn active_port(ports: &[Port], active: Option<&Option<Cow<str>>>) -> Option<usize> {
+	let active = active?.as_ref()?;
+
+	ports.iter().position(|p| p.name == *active)
+}
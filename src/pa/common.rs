@@ -10,7 +10,7 @@ pub use tokio::sync::mpsc;
 pub use super::{errors::PAError, monitor::Monitors, PAInternal, SPEC};
 pub use crate::{
 	entry::{EntryIdentifier, EntryType},
-	models::{EntryUpdate, PulseAudioAction},
+	models::{EntryUpdate, MonitorTarget, PulseAudioAction},
 	prelude::*,
 };
 
@@ -24,6 +24,8 @@ impl From<Facility> for EntryType {
 			Facility::SinkInput => EntryType::SinkInput,
 			Facility::SourceOutput => EntryType::SourceOutput,
 			Facility::Card => EntryType::Card,
+			Facility::Module => EntryType::Module,
+			Facility::Client => EntryType::Client,
 			_ => EntryType::Sink,
 		}
 	}
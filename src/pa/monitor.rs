@@ -1,13 +1,15 @@
 use std::convert::TryInto;
 
-use pulse::stream::PeekResult;
+use pulse::{channelmap::Map, stream::PeekResult};
 
 use super::{common::*, pa_interface::ACTIONS_SX};
-use crate::VARIABLES;
+use crate::{models::Levels, VARIABLES};
 
 pub struct Monitor {
 	stream: Rc<RefCell<Stream>>,
 	exit_sender: cb_channel::Sender<u32>,
+	// None for a mono monitor
+	channel_map: Option<Map>,
 }
 
 pub struct Monitors {
@@ -29,7 +31,7 @@ impl Monitors {
 		&mut self,
 		mainloop: &Rc<RefCell<Mainloop>>,
 		context: &Rc<RefCell<PAContext>>,
-		targets: &HashMap<EntryIdentifier, Option<u32>>,
+		targets: &HashMap<EntryIdentifier, MonitorTarget>,
 	) {
 		// remove failed streams
 		// then send exit signal if stream is unwanted
@@ -45,16 +47,26 @@ impl Monitors {
 				_ => {}
 			};
 
-			if targets.get(ident) == None {
-				let _ = monitor.exit_sender.send(0);
+			match targets.get(ident) {
+				Some(target) if channel_map(target) == monitor.channel_map => {}
+				// recording the wrong channels, closed now so it's recreated below
+				Some(_) => {
+					let mut stream = monitor.stream.borrow_mut();
+					stream.set_read_callback(None);
+					let _ = stream.disconnect();
+					return false;
+				}
+				None => {
+					let _ = monitor.exit_sender.send(0);
+				}
 			}
 
 			true
 		});
 
-		targets.iter().for_each(|(ident, monitor_src)| {
+		targets.iter().for_each(|(ident, target)| {
 			if self.monitors.get(ident).is_none() {
-				self.create_monitor(mainloop, context, *ident, *monitor_src);
+				self.create_monitor(mainloop, context, *ident, target);
 			}
 		});
 	}
@@ -64,7 +76,7 @@ impl Monitors {
 		mainloop: &Rc<RefCell<Mainloop>>,
 		context: &Rc<RefCell<PAContext>>,
 		ident: EntryIdentifier,
-		monitor_src: Option<u32>,
+		target: &MonitorTarget,
 	) {
 		if let Some(count) = self.errors.get(&ident) {
 			if *count >= 5 {
@@ -79,16 +91,18 @@ impl Monitors {
 			return;
 		}
 		let (sx, rx) = cb_channel::unbounded();
+		let channel_map = channel_map(target);
 		if let Ok(stream) = create(
 			&mainloop,
 			&context,
 			&pulse::sample::Spec {
 				format: pulse::sample::Format::FLOAT32NE,
-				channels: 1,
-				rate: (*VARIABLES).get().pa_rate,
+				channels: channel_map.map(|m| m.len()).unwrap_or(1),
+				rate: VARIABLES.read().unwrap().pa_rate,
 			},
+			channel_map.as_ref(),
 			ident,
-			monitor_src,
+			target.source,
 			rx,
 		) {
 			self.monitors.insert(
@@ -96,6 +110,7 @@ impl Monitors {
 				Monitor {
 					stream,
 					exit_sender: sx,
+					channel_map,
 				},
 			);
 			self.errors.remove(&ident);
@@ -113,6 +128,15 @@ impl Monitors {
 	}
 }
 
+// the entry's channel map when monitors record every channel
+fn channel_map(target: &MonitorTarget) -> Option<Map> {
+	if VARIABLES.read().unwrap().pa_monitor_channels && target.channel_map.is_valid() {
+		Some(target.channel_map)
+	} else {
+		None
+	}
+}
+
 fn slice_to_4_bytes(slice: &[u8]) -> [u8; 4] {
 	slice.try_into().expect("slice with incorrect length")
 }
@@ -121,12 +145,15 @@ fn create(
 	p_mainloop: &Rc<RefCell<Mainloop>>,
 	p_context: &Rc<RefCell<PAContext>>,
 	p_spec: &pulse::sample::Spec,
+	p_map: Option<&Map>,
 	ident: EntryIdentifier,
 	source_index: Option<u32>,
 	close_rx: cb_channel::Receiver<u32>,
 ) -> Result<Rc<RefCell<Stream>>> {
 	info!("[PADataInterface] Attempting to create new monitor stream");
 
+	let channels = p_spec.channels as usize;
+
 	let stream_index = if ident.entry_type == EntryType::SinkInput {
 		Some(ident.index)
 	} else {
@@ -134,7 +161,12 @@ fn create(
 	};
 
 	let stream = Rc::new(RefCell::new(
-		match Stream::new(&mut p_context.borrow_mut(), "RsMixer monitor", p_spec, None) {
+		match Stream::new(
+			&mut p_context.borrow_mut(),
+			"RsMixer monitor",
+			p_spec,
+			p_map,
+		) {
 			Some(stream) => stream,
 			None => {
 				return Err(PAError::StreamCreateError)
@@ -183,7 +215,7 @@ fn create(
 			tlength: std::u32::MAX,
 			prebuf: std::u32::MAX,
 			minreq: 0,
-			fragsize: (*VARIABLES).get().pa_frag_size,
+			fragsize: VARIABLES.read().unwrap().pa_frag_size,
 		}),
 		pulse::stream::FlagSet::PEAK_DETECT | pulse::stream::FlagSet::ADJUST_LATENCY,
 	) {
@@ -244,15 +276,19 @@ fn create(
                     match unsafe{ (*(*stream_ref.as_ptr()).as_ptr()).peek() } {
                         Ok(res) => match res {
                             PeekResult::Data(data) => {
-                                let count = data.len() / 4;
-                                let mut peak = 0.0;
-                                for c in 0..count {
-                                    let data_slice = slice_to_4_bytes(&data[c * 4 .. (c + 1) * 4]);
-                                    peak += f32::from_ne_bytes(data_slice).abs();
-                                }
-                                peak = peak / count as f32;
+                                // samples of all channels are interleaved
+                                let samples: Vec<f32> = data
+                                    .chunks_exact(4)
+                                    .map(|c| f32::from_ne_bytes(slice_to_4_bytes(c)))
+                                    .collect();
+                                let levels = (0..channels)
+                                    .map(|c| {
+                                        let channel: Vec<f32> = samples.iter().skip(c).step_by(channels).copied().collect();
+                                        Levels::from_samples(&channel)
+                                    })
+                                    .collect();
 
-                                if (*ACTIONS_SX).get().send(EntryUpdate::PeakVolumeUpdate(ident, peak)).is_err() {
+                                if (*ACTIONS_SX).get().send(EntryUpdate::PeakVolumeUpdate(ident, levels)).is_err() {
                                     disconnect_stream();
                                 }
 
@@ -1,4 +1,4 @@
-use super::{callbacks, common::*};
+use super::{callbacks, common::*, pa_interface::ACTIONS_SX};
 
 pub fn handle_command(
 	cmd: PulseAudioAction,
@@ -18,6 +18,9 @@ pub fn handle_command(
 		PulseAudioAction::ChangeCardProfile(ident, profile) => {
 			change_card_profile(ident, profile, &context);
 		}
+		PulseAudioAction::SetPort(ident, port) => {
+			set_port(ident, port, context);
+		}
 		PulseAudioAction::SetVolume(ident, vol) => {
 			set_volume(ident, vol, &context);
 		}
@@ -27,6 +30,18 @@ pub fn handle_command(
 		PulseAudioAction::KillEntry(ident) => {
 			kill_entry(ident, &context);
 		}
+		PulseAudioAction::SetDefault(ident, name) => {
+			set_default(ident, &name, context);
+		}
+		PulseAudioAction::LoadModule(name, argument) => {
+			load_module(name, argument, context);
+		}
+		PulseAudioAction::UnloadModule(ident) => {
+			unload_module(ident, context);
+		}
+		PulseAudioAction::Sync => {
+			callbacks::sync(context);
+		}
 		PulseAudioAction::Shutdown => {
 			//@TODO disconnect monitors
 			return None;
@@ -69,6 +84,19 @@ fn change_card_profile(ident: EntryIdentifier, profile: String, context: &Rc<Ref
 		.set_card_profile_by_index(ident.index, &profile[..], None);
 }
 
+fn set_port(ident: EntryIdentifier, port: String, context: &Rc<RefCell<PAContext>>) {
+	let mut introspector = context.borrow_mut().introspect();
+	match ident.entry_type {
+		EntryType::Sink => {
+			introspector.set_sink_port_by_index(ident.index, &port, None);
+		}
+		EntryType::Source => {
+			introspector.set_source_port_by_index(ident.index, &port, None);
+		}
+		_ => {}
+	};
+}
+
 fn move_entry_to_parent(
 	ident: EntryIdentifier,
 	parent: EntryIdentifier,
@@ -115,6 +143,49 @@ fn set_suspend(ident: EntryIdentifier, suspend: bool, context: &Rc<RefCell<PACon
 	};
 }
 
+fn set_default(ident: EntryIdentifier, name: &str, context: &Rc<RefCell<PAContext>>) {
+	match ident.entry_type {
+		EntryType::Sink => {
+			context.borrow_mut().set_default_sink(name, |_| {});
+		}
+		EntryType::Source => {
+			context.borrow_mut().set_default_source(name, |_| {});
+		}
+		_ => {}
+	};
+}
+
+fn load_module(name: String, argument: String, context: &Rc<RefCell<PAContext>>) {
+	let description = format!("{} {}", name, argument);
+
+	context
+		.borrow_mut()
+		.introspect()
+		.load_module(&name, &argument, move |index| {
+			if index == pulse::def::INVALID_INDEX {
+				error!("[PAInterface] Loading module {} failed", description);
+				return;
+			}
+
+			let _ = (*ACTIONS_SX)
+				.get()
+				.send(EntryUpdate::ModuleLoaded(EntryIdentifier::new(
+					EntryType::Module,
+					index,
+				)));
+		});
+}
+
+fn unload_module(ident: EntryIdentifier, context: &Rc<RefCell<PAContext>>) {
+	if ident.entry_type != EntryType::Module {
+		return;
+	}
+	context
+		.borrow_mut()
+		.introspect()
+		.unload_module(ident.index, |_| {});
+}
+
 fn kill_entry(ident: EntryIdentifier, context: &Rc<RefCell<PAContext>>) {
 	let mut introspector = context.borrow_mut().introspect();
 	match ident.entry_type {
@@ -0,0 +1,163 @@
+use pulse::volume::ChannelVolumes;
+
+use super::entries::*;
+use crate::{
+	cli::{
+		list::{render, Format},
+		parse_volume, set_volume,
+		target::find_entry,
+		CliError,
+	},
+	entry::{Entries, EntryType},
+	util::{channel_volume_to_percent, percent_to_volume},
+};
+
+static PLAY_TYPES: [EntryType; 2] = [EntryType::Sink, EntryType::SinkInput];
+
+fn entries() -> Entries {
+	collect_entries(vec![
+		with_device_name(sink(0, "Speakers"), "alsa_output.pci.analog-stereo"),
+		with_device_name(sink(1, "Dock Speakers"), "alsa_output.usb-dock"),
+		sink_input(5, "Firefox", 0),
+		sink_input(6, "Music player", 1),
+	])
+}
+
+fn found(target: &str) -> Result<(EntryType, u32), String> {
+	find_entry(&entries(), target, &PLAY_TYPES)
+		.map(|e| (e.entry_type, e.entry_ident.index))
+		.map_err(|e| e.to_string())
+}
+
+#[test]
+fn targets_are_matched_by_index() {
+	assert_eq!(found("sink:1"), Ok((EntryType::Sink, 1)));
+	assert_eq!(found("sink-input:5"), Ok((EntryType::SinkInput, 5)));
+
+	assert_eq!(
+		found("sink:4"),
+		Err("'sink:4' does not match any entry".to_string())
+	);
+	// sources aren't looked for
+	assert_eq!(
+		found("source:0"),
+		Err("'source:0' does not match any entry".to_string())
+	);
+}
+
+#[test]
+fn targets_are_matched_by_name() {
+	assert_eq!(found("alsa_output.usb-dock"), Ok((EntryType::Sink, 1)));
+	// not by a part of it
+	assert_eq!(
+		found("alsa_output"),
+		Err("'alsa_output' does not match any entry".to_string())
+	);
+}
+
+#[test]
+fn targets_are_matched_by_description() {
+	// an exact description wins over the ones containing it
+	assert_eq!(found("speakers"), Ok((EntryType::Sink, 0)));
+	assert_eq!(found("dock"), Ok((EntryType::Sink, 1)));
+	assert_eq!(found("PLAYER"), Ok((EntryType::SinkInput, 6)));
+
+	assert_eq!(
+		found("e"),
+		Err("'e' matches more than one entry".to_string())
+	);
+	assert_eq!(
+		found("Headphones"),
+		Err("'Headphones' does not match any entry".to_string())
+	);
+}
+
+#[test]
+fn volumes_are_absolute_or_relative() {
+	assert_eq!(parse_volume("40%", 50).ok(), Some(40));
+	assert_eq!(parse_volume("40", 50).ok(), Some(40));
+	assert_eq!(parse_volume("+5%", 50).ok(), Some(55));
+	assert_eq!(parse_volume("-5%", 50).ok(), Some(45));
+	assert_eq!(parse_volume("+32767%", 50).ok(), Some(i16::MAX));
+}
+
+#[test]
+fn malformed_volumes_are_rejected() {
+	for volume in &["", "%", "loud", "5%%", "+-5%", "4 0%", "40000%", "0.5"] {
+		assert!(
+			matches!(parse_volume(volume, 50), Err(CliError::InvalidVolume(v)) if v == *volume),
+			"{} was accepted",
+			volume
+		);
+	}
+}
+
+#[test]
+fn set_volume_keeps_the_balance() {
+	let mut volume = ChannelVolumes::default();
+	volume.set(2, pulse::volume::Volume(percent_to_volume(40, 150)));
+	volume.get_mut()[1].0 = percent_to_volume(60, 150);
+
+	let percents = |v: ChannelVolumes| {
+		v.get()
+			.iter()
+			.map(|c| channel_volume_to_percent(*c))
+			.collect::<Vec<_>>()
+	};
+
+	assert_eq!(
+		percents(set_volume(volume, "70%", 150).unwrap()),
+		vec![60, 80]
+	);
+	assert_eq!(
+		percents(set_volume(volume, "-10%", 150).unwrap()),
+		vec![30, 50]
+	);
+	// the louder channel stops at the maximum
+	assert_eq!(
+		percents(set_volume(volume, "+100%", 150).unwrap()),
+		vec![140, 150]
+	);
+}
+
+#[test]
+fn json_list_has_every_entry() {
+	let entries = collect_entries(vec![
+		sink(0, "Speakers"),
+		sink_input(5, "Firefox\tYouTube\nmusic", 0),
+	]);
+
+	assert_eq!(
+		render(&entries, Format::Json).unwrap(),
+		concat!(
+			r#"{"sinks":[{"index":0,"name":"Speakers","volume":[50,50],"mute":false,"#,
+			r#""parent":null,"monitor_source":null,"suspended":false,"active_port":null,"#,
+			r#""ports":[]}],"sink_inputs":[{"index":5,"name":"Firefox\tYouTube\nmusic","#,
+			r#""volume":[50,50],"mute":false,"parent":0,"monitor_source":null,"#,
+			r#""suspended":false,"active_port":null,"ports":[]}],"sources":[],"#,
+			r#""source_outputs":[],"cards":[]}"#,
+			"\n"
+		)
+	);
+}
+
+#[test]
+fn tsv_list_has_a_line_per_entry() {
+	let entries = collect_entries(vec![
+		sink(0, "Speakers"),
+		sink_input(5, "Firefox\tYouTube\nmusic", 0),
+		card(2, "Built-in Audio", &["analog", "hdmi"], Some(1)),
+	]);
+
+	// tabs and newlines of names would break the columns and lines
+	assert_eq!(
+		render(&entries, Format::Tsv).unwrap(),
+		concat!(
+			"sink\t0\tSpeakers\t50,50\tfalse\t\t\tfalse\t\t\n",
+			"sink-input\t5\tFirefox YouTube music\t50,50\tfalse\t0\t\tfalse\t\t\n",
+			"card\t2\tBuilt-in Audio\t\t\t\t\t\thdmi\tanalog,hdmi\n",
+		)
+	);
+
+	assert_eq!(render(&Entries::default(), Format::Tsv).unwrap(), "");
+}
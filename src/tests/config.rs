@@ -0,0 +1,57 @@
+use std::{collections::HashMap, sync::RwLock};
+
+use crate::{
+	config::{keys_mouse::try_string_to_event, RsMixerConfig, Variables},
+	models::{Style, UserAction},
+	multimap::MultiMap,
+	VERSION,
+};
+
+// the current version, older ones would be upgraded and written back
+fn config(bindings: &str, normal: &str) -> RsMixerConfig {
+	toml::from_str(&format!(
+		"version = '{}'\n[bindings]\n{}\n[colors.normal]\nfg = '{}'\n",
+		VERSION, bindings, normal
+	))
+	.unwrap()
+}
+
+fn bound(bindings: &RwLock<MultiMap<crate::models::InputEvent, UserAction>>, key: &str) -> bool {
+	bindings
+		.read()
+		.unwrap()
+		.get_vec(&try_string_to_event(key).unwrap())
+		.is_some()
+}
+
+#[test]
+fn invalid_config_keeps_the_previous_one() {
+	let styles = RwLock::new(HashMap::new());
+	let bindings = RwLock::new(MultiMap::new());
+	let variables = RwLock::new(Variables::default());
+
+	config("q = ['exit']", "white")
+		.replace(&styles, &bindings, &variables)
+		.unwrap();
+	assert!(bound(&bindings, "q"));
+	let white = styles.read().unwrap().clone();
+	assert!(white.contains_key(&Style::Normal));
+
+	// an unknown action, then a color that isn't hex
+	assert!(config("w = ['no_such_action']", "red")
+		.replace(&styles, &bindings, &variables)
+		.is_err());
+	assert!(config("w = ['exit']", "#zzzzzz")
+		.replace(&styles, &bindings, &variables)
+		.is_err());
+	assert!(bound(&bindings, "q"));
+	assert!(!bound(&bindings, "w"));
+	assert!(*styles.read().unwrap() == white);
+
+	config("w = ['exit']", "red")
+		.replace(&styles, &bindings, &variables)
+		.unwrap();
+	assert!(bound(&bindings, "w"));
+	assert!(!bound(&bindings, "q"));
+	assert!(*styles.read().unwrap() != white);
+}
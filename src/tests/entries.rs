@@ -0,0 +1,200 @@
+use pulse::{
+	channelmap::Map,
+	volume::{ChannelVolumes, Volume},
+};
+
+use crate::{
+	entry::{CardProfile, Entries, Entry, EntryIdentifier, EntryType, Port},
+	models::{EntryUpdate, Levels},
+	ui::Rect,
+	util::percent_to_volume,
+};
+
+// EntryUpdates looking like the ones sent by pa::callbacks
+
+pub fn sink(index: u32, name: &str) -> EntryUpdate {
+	play_entry(EntryType::Sink, index, name, None)
+}
+
+pub fn sink_input(index: u32, name: &str, sink: u32) -> EntryUpdate {
+	play_entry(EntryType::SinkInput, index, name, Some(sink))
+}
+
+pub fn source(index: u32, name: &str) -> EntryUpdate {
+	play_entry(EntryType::Source, index, name, None)
+}
+
+pub fn card(index: u32, name: &str, profiles: &[&str], selected: Option<usize>) -> EntryUpdate {
+	let profiles = profiles.iter().map(|p| (*p, 0, 0)).collect::<Vec<_>>();
+
+	card_with_devices(index, name, &profiles, selected)
+}
+
+// profiles are given with the number of sinks and sources they create
+pub fn card_with_devices(
+	index: u32,
+	name: &str,
+	profiles: &[(&str, u32, u32)],
+	selected: Option<usize>,
+) -> EntryUpdate {
+	let profiles = profiles
+		.iter()
+		.map(|(p, n_sinks, n_sources)| CardProfile {
+			name: p.to_string(),
+			description: p.to_string(),
+			n_sinks: *n_sinks,
+			n_sources: *n_sources,
+			priority: 0,
+			#[cfg(feature = "pa_v13")]
+			available: true,
+			area: Rect::default(),
+			is_selected: false,
+		})
+		.collect();
+
+	let entry = Entry::new_card_entry(index, name.to_string(), profiles, selected);
+
+	EntryUpdate::EntryUpdate(entry.entry_ident, Box::new(entry))
+}
+
+pub fn client(index: u32, name: &str) -> EntryUpdate {
+	let entry = Entry::new_client_entry(index, name.to_string(), None, None);
+
+	EntryUpdate::EntryUpdate(entry.entry_ident, Box::new(entry))
+}
+
+pub fn with_client(update: EntryUpdate, client: u32) -> EntryUpdate {
+	match update {
+		EntryUpdate::EntryUpdate(ident, entry) => {
+			EntryUpdate::EntryUpdate(ident, Box::new(entry.client(Some(client))))
+		}
+		update => update,
+	}
+}
+
+pub fn module(index: u32, name: &str, argument: &str) -> EntryUpdate {
+	let entry = Entry::new_module_entry(index, name.to_string(), argument.to_string());
+
+	EntryUpdate::EntryUpdate(entry.entry_ident, Box::new(entry))
+}
+
+// ports are named after their descriptions, with their availability
+pub fn with_ports(
+	update: EntryUpdate,
+	ports: &[(&str, bool)],
+	active: Option<usize>,
+) -> EntryUpdate {
+	let ports = ports
+		.iter()
+		.map(|(name, available)| Port {
+			name: name.to_string(),
+			description: name.to_string(),
+			available: *available,
+		})
+		.collect();
+
+	match update {
+		EntryUpdate::EntryUpdate(ident, entry) => {
+			EntryUpdate::EntryUpdate(ident, Box::new(entry.ports(ports, active)))
+		}
+		update => update,
+	}
+}
+
+// name of a sink or source in pulseaudio
+pub fn with_device_name(update: EntryUpdate, device_name: &str) -> EntryUpdate {
+	match update {
+		EntryUpdate::EntryUpdate(ident, entry) => {
+			EntryUpdate::EntryUpdate(ident, Box::new(entry.device_name(device_name.to_string())))
+		}
+		update => update,
+	}
+}
+
+// details are shown in the given order, properties sorted by key
+pub fn with_details(
+	update: EntryUpdate,
+	details: &[(&str, &str)],
+	properties: &[(&str, &str)],
+) -> EntryUpdate {
+	let details = details
+		.iter()
+		.map(|(k, v)| (k.to_string(), v.to_string()))
+		.collect();
+	let properties = properties
+		.iter()
+		.map(|(k, v)| (k.to_string(), v.to_string()))
+		.collect();
+
+	match update {
+		EntryUpdate::EntryUpdate(ident, entry) => EntryUpdate::EntryUpdate(
+			ident,
+			Box::new(entry.details(details).properties(properties)),
+		),
+		update => update,
+	}
+}
+
+pub fn removed(entry_type: EntryType, index: u32) -> EntryUpdate {
+	EntryUpdate::EntryRemoved(ident(entry_type, index))
+}
+
+// block of samples from a mono monitor, levels in dBFS
+pub fn levels(entry_type: EntryType, index: u32, peak: f32, average: f32) -> EntryUpdate {
+	channel_levels(entry_type, index, &[(peak, average)])
+}
+
+// peak and average of every channel
+pub fn channel_levels(entry_type: EntryType, index: u32, channels: &[(f32, f32)]) -> EntryUpdate {
+	EntryUpdate::PeakVolumeUpdate(
+		ident(entry_type, index),
+		channels
+			.iter()
+			.map(|&(peak, average)| Levels { peak, average })
+			.collect(),
+	)
+}
+
+// entries as the event loop has them after the updates
+pub fn collect_entries(updates: Vec<EntryUpdate>) -> Entries {
+	let mut entries = Entries::default();
+	for update in updates {
+		if let EntryUpdate::EntryUpdate(ident, entry) = update {
+			entries.insert(ident, *entry);
+		}
+	}
+
+	entries
+}
+
+pub fn ident(entry_type: EntryType, index: u32) -> EntryIdentifier {
+	EntryIdentifier::new(entry_type, index)
+}
+
+// stereo, 50%
+pub fn volume() -> ChannelVolumes {
+	let mut volume = ChannelVolumes::default();
+	volume.set(2, Volume(percent_to_volume(50, 150)));
+
+	volume
+}
+
+fn play_entry(entry_type: EntryType, index: u32, name: &str, parent: Option<u32>) -> EntryUpdate {
+	let mut channel_map = Map::default();
+	channel_map.init_stereo();
+
+	let entry = Entry::new_play_entry(
+		entry_type,
+		index,
+		name.to_string(),
+		parent,
+		false,
+		volume(),
+		channel_map,
+		None,
+		parent.filter(|_| entry_type == EntryType::SinkInput),
+		false,
+	);
+
+	EntryUpdate::EntryUpdate(entry.entry_ident, Box::new(entry))
+}
@@ -0,0 +1,196 @@
+use super::{entries::*, harness::Harness};
+use crate::{
+	entry::EntryType,
+	models::{EntryUpdate, Event, EventKind, PAStatus, PageType, UserAction},
+};
+
+#[test]
+fn events_describe_what_happened() {
+	let event = |kind, name| Event::new(kind, ident(EntryType::Sink, 3), name).text();
+
+	assert_eq!(event(EventKind::Added, "Jabra"), "Sink 'Jabra' added");
+	assert_eq!(event(EventKind::Removed, "Jabra"), "Sink 'Jabra' removed");
+	assert_eq!(
+		event(EventKind::DefaultChanged, "Jabra"),
+		"Default sink changed to 'Jabra'"
+	);
+	assert_eq!(event(EventKind::DefaultChanged, ""), "Default sink changed");
+}
+
+#[tokio::test]
+async fn hotplugged_devices_are_shown_as_toasts() {
+	let harness = Harness::start(vec![
+		sink(0, "Speakers"),
+		sink_input(5, "Firefox", 0),
+		EntryUpdate::DefaultChanged(ident(EntryType::Sink, 0)),
+	]);
+	harness.wait_for(|s| s.page_entries.len() == 2).await;
+	// the first default isn't a change
+	assert!(!harness.wait_for(|_| true).await.screen_contains("Default"));
+
+	harness.send(EntryUpdate::EntryCreated(ident(EntryType::Sink, 2)));
+	harness.send(sink(2, "Jabra"));
+	harness
+		.wait_for(|s| s.screen_contains("Sink 'Jabra' added"))
+		.await;
+
+	harness.send(EntryUpdate::DefaultChanged(ident(EntryType::Sink, 2)));
+	harness
+		.wait_for(|s| s.screen_contains("Default sink changed to 'Jabra'"))
+		.await;
+
+	harness.send(EntryUpdate::EntryRemoved(ident(EntryType::Sink, 2)));
+	harness
+		.wait_for(|s| s.screen_contains("Sink 'Jabra' removed"))
+		.await;
+
+	// streams come and go all the time
+	harness.send(EntryUpdate::EntryCreated(ident(EntryType::SinkInput, 6)));
+	harness.send(sink_input(6, "Music player", 0));
+	let snapshot = harness
+		.wait_for(|s| s.screen_contains("Music player"))
+		.await;
+	assert!(snapshot.screen_contains("Sink 'Jabra' removed"));
+
+	harness.shutdown().await;
+}
+
+#[tokio::test]
+async fn log_page_lists_events_by_type() {
+	let harness = Harness::start(vec![sink(0, "Speakers"), sink_input(5, "Firefox", 0)]);
+	harness.wait_for(|s| s.page_entries.len() == 2).await;
+
+	harness.send(UserAction::ChangePage(PageType::Log));
+	harness
+		.wait_for(|s| s.screen_contains("Showing all events"))
+		.await;
+
+	// an app reconnecting its stream
+	harness.send(EntryUpdate::EntryCreated(ident(EntryType::SinkInput, 6)));
+	harness.send(sink_input(6, "Music player", 0));
+	harness.send(EntryUpdate::EntryChanged(ident(EntryType::Sink, 0)));
+	harness.send(removed(EntryType::SinkInput, 6));
+	let snapshot = harness
+		.wait_for(|s| s.screen_contains("removed  sink input        #6  Music player"))
+		.await;
+	assert!(snapshot.screen_contains("new      sink input        #6  Music player"));
+	assert!(snapshot.screen_contains("changed  sink              #0  Speakers"));
+
+	harness.send(UserAction::MoveRight);
+	let snapshot = harness
+		.wait_for(|s| s.screen_contains("Showing sink events"))
+		.await;
+	assert!(snapshot.screen_contains("Speakers"));
+	assert!(!snapshot.screen_contains("Music player"));
+
+	harness.shutdown().await;
+}
+
+#[tokio::test]
+async fn log_page_keeps_connection_losses() {
+	let harness = Harness::start(vec![sink(0, "Speakers")]);
+	harness.wait_for(|s| s.page_entries.len() == 1).await;
+
+	harness.send(UserAction::ChangePage(PageType::Log));
+	harness.send(PAStatus::PulseAudioDisconnected);
+	harness.send(PAStatus::ConnectToPulseAudio);
+	let snapshot = harness
+		.wait_for(|s| s.screen_contains("Connecting to PulseAudio"))
+		.await;
+	assert!(snapshot.screen_contains("Disconnected from PulseAudio"));
+	assert!(snapshot.screen_contains("Log"));
+
+	harness.send(UserAction::ChangeFilterValue("disconn".to_string(), 7));
+	let snapshot = harness
+		.wait_for(|s| !s.screen_contains("Connecting to PulseAudio"))
+		.await;
+	assert!(snapshot.screen_contains("Disconnected from PulseAudio"));
+
+	harness.shutdown().await;
+}
+
+#[tokio::test]
+async fn reconnecting_goes_back_to_the_output_page() {
+	let harness = Harness::start(vec![sink(0, "Speakers")]);
+	harness.wait_for(|s| s.page_entries.len() == 1).await;
+
+	harness.send(UserAction::ChangePage(PageType::Input));
+	harness.wait_for(|s| s.page == PageType::Input).await;
+
+	harness.send(PAStatus::PulseAudioDisconnected);
+	harness.send(PAStatus::ConnectToPulseAudio);
+	harness.wait_for(|s| s.page == PageType::Output).await;
+
+	harness.shutdown().await;
+}
+
+// needs dbus-daemon, a notification daemon is stood in for on a private session bus
+#[cfg(feature = "notifications")]
+#[test]
+fn notifications_are_sent_to_the_session_bus() {
+	use std::{
+		io::{BufRead, BufReader},
+		process::{Command, Stdio},
+		sync::mpsc,
+		time::Duration,
+	};
+
+	use dbus::{
+		blocking::Connection,
+		channel::{Channel, MatchingReceiver, Sender},
+		message::MatchRule,
+	};
+
+	use crate::notifications;
+
+	let mut daemon = match Command::new("dbus-daemon")
+		.args(["--session", "--print-address", "--nofork", "--nopidfile"])
+		.stdout(Stdio::piped())
+		.stderr(Stdio::null())
+		.spawn()
+	{
+		Ok(daemon) => daemon,
+		Err(_) => {
+			eprintln!("dbus-daemon not found, skipping");
+			return;
+		}
+	};
+	let mut address = String::new();
+	BufReader::new(daemon.stdout.take().unwrap())
+		.read_line(&mut address)
+		.unwrap();
+	let address = address.trim().to_string();
+
+	let mut channel = Channel::open_private(&address).unwrap();
+	channel.register().unwrap();
+	let server = Connection::from(channel);
+	server
+		.request_name("org.freedesktop.Notifications", false, true, false)
+		.unwrap();
+
+	let (summary_sx, summary_rx) = mpsc::channel();
+	server.start_receive(
+		MatchRule::new_method_call(),
+		Box::new(move |msg, conn| {
+			let (_, _, _, summary): (String, u32, String, String) = msg.read4().unwrap();
+			summary_sx.send(summary).unwrap();
+			let _ = conn.send(msg.method_return().append1(7u32));
+			true
+		}),
+	);
+
+	std::env::set_var("DBUS_SESSION_BUS_ADDRESS", &address);
+	let client = std::thread::spawn(|| notifications::notify("Sink 'Jabra' added"));
+
+	for _ in 0..50 {
+		server.process(Duration::from_millis(100)).unwrap();
+		if client.is_finished() {
+			break;
+		}
+	}
+
+	assert_eq!(client.join().unwrap().unwrap(), 7);
+	assert_eq!(summary_rx.try_recv().unwrap(), "Sink 'Jabra' added");
+
+	let _ = daemon.kill();
+}
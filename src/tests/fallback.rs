@@ -0,0 +1,109 @@
+use std::collections::HashSet;
+
+use super::entries::*;
+use crate::{
+	entry::EntryType,
+	models::{reroute_from, reroute_to, PulseAudioAction},
+};
+
+fn preferred(names: &[&str]) -> Vec<String> {
+	names.iter().map(|n| n.to_string()).collect()
+}
+
+#[test]
+fn streams_of_a_removed_sink_fall_back_to_the_preferred_one() {
+	let preferred = preferred(&["Jabra", "alsa_output.usb-dock", "Speakers"]);
+	// the headset is gone already, its streams still point to it
+	let entries = collect_entries(vec![
+		sink(0, "Speakers"),
+		with_device_name(sink(1, "Dock"), "alsa_output.usb-dock"),
+		sink(3, "HDMI"),
+		sink_input(5, "Firefox", 2),
+		sink_input(6, "Music player", 2),
+		sink_input(7, "Game", 3),
+	]);
+	let defaults = [ident(EntryType::Sink, 2)].iter().copied().collect();
+
+	let actions = reroute_from(&entries, &defaults, &ident(EntryType::Sink, 2), &preferred);
+
+	let dock = ident(EntryType::Sink, 1);
+	assert_eq!(
+		actions,
+		vec![
+			PulseAudioAction::MoveEntryToParent(ident(EntryType::SinkInput, 5), dock),
+			PulseAudioAction::MoveEntryToParent(ident(EntryType::SinkInput, 6), dock),
+			PulseAudioAction::SetDefault(dock, "alsa_output.usb-dock".to_string()),
+		]
+	);
+
+	// nothing to do without a list
+	assert!(reroute_from(&entries, &defaults, &ident(EntryType::Sink, 2), &[]).is_empty());
+}
+
+#[test]
+fn rerouted_streams_return_to_a_better_sink() {
+	let preferred = preferred(&["Jabra", "Speakers"]);
+	let entries = collect_entries(vec![
+		sink(0, "Speakers"),
+		sink(3, "HDMI"),
+		with_device_name(sink(4, "JABRA"), "bluez_sink.jabra"),
+		sink_input(5, "Firefox", 0),
+		sink_input(6, "Music player", 0),
+		sink_input(7, "Game", 3),
+	]);
+	let defaults = [ident(EntryType::Sink, 0)].iter().copied().collect();
+	// the game was moved to HDMI on purpose and stays there
+	let rerouted = [ident(EntryType::SinkInput, 5)]
+		.iter()
+		.copied()
+		.collect::<HashSet<_>>();
+	// the speakers became the default when the headset went away
+	let rerouted_defaults = [EntryType::Sink].iter().copied().collect();
+
+	let jabra = ident(EntryType::Sink, 4);
+	let actions = reroute_to(
+		&entries,
+		&defaults,
+		&rerouted,
+		&rerouted_defaults,
+		&jabra,
+		&preferred,
+	);
+	assert_eq!(
+		actions,
+		vec![
+			PulseAudioAction::MoveEntryToParent(ident(EntryType::SinkInput, 5), jabra),
+			PulseAudioAction::SetDefault(jabra, "bluez_sink.jabra".to_string()),
+		]
+	);
+
+	// a default picked by hand stays
+	let actions = reroute_to(
+		&entries,
+		&defaults,
+		&rerouted,
+		&HashSet::new(),
+		&jabra,
+		&preferred,
+	);
+	assert_eq!(
+		actions,
+		vec![PulseAudioAction::MoveEntryToParent(
+			ident(EntryType::SinkInput, 5),
+			jabra
+		)]
+	);
+
+	// a device ranked lower doesn't take anything
+	let speakers = ident(EntryType::Sink, 0);
+	let defaults = [jabra].iter().copied().collect();
+	assert!(reroute_to(
+		&entries,
+		&defaults,
+		&rerouted,
+		&rerouted_defaults,
+		&speakers,
+		&preferred
+	)
+	.is_empty());
+}
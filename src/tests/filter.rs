@@ -0,0 +1,34 @@
+use crate::util::filter_match;
+
+#[test]
+fn continuous_parts_match_first() {
+	assert_eq!(filter_match("fox", "Firefox"), Some(vec![4, 5, 6]));
+	// the subsequence f-o-x would start at 0, the continuous part wins
+	assert_eq!(filter_match("fox", "Foxes of Firefox"), Some(vec![0, 1, 2]));
+	assert_eq!(filter_match("", "Firefox"), Some(Vec::new()));
+}
+
+#[test]
+fn subsequences_match_when_there_is_no_continuous_part() {
+	assert_eq!(filter_match("ffx", "Firefox"), Some(vec![0, 4, 6]));
+	assert_eq!(
+		filter_match("mplr", "Music player"),
+		Some(vec![0, 6, 7, 11])
+	);
+}
+
+#[test]
+fn matching_ignores_case() {
+	assert_eq!(filter_match("FIRE", "firefox"), Some(vec![0, 1, 2, 3]));
+	assert_eq!(
+		filter_match("hdmi", "HDMI / DisplayPort"),
+		Some(vec![0, 1, 2, 3])
+	);
+}
+
+#[test]
+fn missing_characters_do_not_match() {
+	assert_eq!(filter_match("chrome", "Firefox"), None);
+	assert_eq!(filter_match("xof", "Firefox"), None);
+	assert_eq!(filter_match("Firefox Nightly", "Firefox"), None);
+}
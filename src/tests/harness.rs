@@ -0,0 +1,256 @@
+use std::{
+	any::Any,
+	io,
+	pin::Pin,
+	sync::{Arc, Mutex},
+	time::Duration,
+};
+
+use futures::Future;
+use tokio::task::JoinHandle;
+
+use crate::{
+	action_handlers,
+	actor_system::{self, prelude::*},
+	entry::EntryIdentifier,
+	models::{EntryUpdate, PageType, PulseAudioAction, RSState, UIMode},
+	prelude::*,
+	ui,
+};
+
+pub const SCREEN_WIDTH: u16 = 60;
+pub const SCREEN_HEIGHT: u16 = 20;
+
+// what the event loop looked like after handling its last message
+#[derive(Clone, Debug)]
+pub struct Snapshot {
+	pub screen: Vec<String>,
+	pub ui_mode: UIMode,
+	pub page: PageType,
+	pub selected: Option<EntryIdentifier>,
+	pub page_entries: Vec<EntryIdentifier>,
+	pub module_input: String,
+	pub volume_input: String,
+}
+
+impl Snapshot {
+	fn new(state: &RSState) -> Self {
+		Self {
+			screen: state.ui.buffer.lines(),
+			ui_mode: state.ui_mode.clone(),
+			page: state.current_page,
+			selected: state.page_entries.get_selected(),
+			page_entries: state.page_entries.entries.clone(),
+			module_input: state.module_input.value.clone(),
+			volume_input: state.input_exact_volume.value.clone(),
+		}
+	}
+
+	pub fn screen_contains(&self, text: &str) -> bool {
+		self.screen.iter().any(|l| l.contains(text))
+	}
+
+	// screen with trailing whitespace removed, for comparing with expected output
+	pub fn text(&self) -> String {
+		self.screen
+			.iter()
+			.map(|l| l.trim_end())
+			.collect::<Vec<&str>>()
+			.join("\n")
+	}
+}
+
+// runs the actor system with the real event loop handlers, rendering
+// into a buffer instead of a terminal, and a fake "pulseaudio" actor
+pub struct Harness {
+	ctx: Ctx,
+	worker: JoinHandle<Result<()>>,
+	snapshot: Arc<Mutex<Option<Snapshot>>>,
+	actions: Arc<Mutex<Vec<PulseAudioAction>>>,
+}
+
+impl Harness {
+	// script is sent to the event loop when it asks for the current state
+	pub fn start(script: Vec<EntryUpdate>) -> Self {
+		let (mut ctx, worker) = actor_system::new();
+		let worker = worker.start();
+
+		let snapshot = Arc::new(Mutex::new(None));
+		let actions = Arc::new(Mutex::new(Vec::new()));
+
+		// registered first, so the event loop's first request isn't lost
+		let mock_actions = Arc::clone(&actions);
+		let mock_factory = move || {
+			Actor::Eventful(Box::new(MockPulseActor {
+				script: script.clone(),
+				actions: Arc::clone(&mock_actions),
+			}))
+		};
+		ActorItem::new("pulseaudio", Box::leak(Box::new(mock_factory)))
+			.register_and_start(&mut ctx);
+
+		let event_loop_snapshot = Arc::clone(&snapshot);
+		let event_loop_factory = move || {
+			Actor::Eventful(Box::new(HeadlessEventLoop {
+				state: RSState::default(),
+				snapshot: Arc::clone(&event_loop_snapshot),
+			}))
+		};
+		ActorItem::new("event_loop", Box::leak(Box::new(event_loop_factory)))
+			.register_and_start(&mut ctx);
+
+		Self {
+			ctx,
+			worker,
+			snapshot,
+			actions,
+		}
+	}
+
+	pub fn send<T: Any + Send + Sync + 'static>(&self, msg: T) {
+		self.ctx.send_to("event_loop", msg);
+	}
+
+	// waits until the event loop gets to a state matching the predicate
+	pub async fn wait_for<F: Fn(&Snapshot) -> bool>(&self, predicate: F) -> Snapshot {
+		let mut last = None;
+
+		for _ in 0..200 {
+			if let Some(snapshot) = &*self.snapshot.lock().unwrap() {
+				if predicate(snapshot) {
+					return snapshot.clone();
+				}
+				last = Some(snapshot.clone());
+			}
+
+			tokio::time::sleep(Duration::from_millis(10)).await;
+		}
+
+		match last {
+			Some(snapshot) => panic!(
+				"timed out waiting for the event loop, last state: {:?} {:?} {:?}\n{}",
+				snapshot.ui_mode,
+				snapshot.selected,
+				snapshot.page_entries,
+				snapshot.text()
+			),
+			None => panic!("timed out waiting for the event loop to start"),
+		}
+	}
+
+	// waits until the fake pulseaudio receives `count` actions and returns them,
+	// without actions sent only to keep peak volume monitors and state in sync
+	pub async fn wait_for_actions(&self, count: usize) -> Vec<PulseAudioAction> {
+		for _ in 0..200 {
+			let actions = self.actions();
+			if actions.len() >= count {
+				return actions;
+			}
+
+			tokio::time::sleep(Duration::from_millis(10)).await;
+		}
+
+		panic!(
+			"timed out waiting for {} actions, received: {:?}",
+			count,
+			self.actions()
+		);
+	}
+
+	pub fn actions(&self) -> Vec<PulseAudioAction> {
+		self.actions
+			.lock()
+			.unwrap()
+			.iter()
+			.filter(|a| {
+				!matches!(
+					a,
+					PulseAudioAction::CreateMonitors(_)
+						| PulseAudioAction::RequestPulseAudioState
+						| PulseAudioAction::Sync
+				)
+			})
+			.cloned()
+			.collect()
+	}
+
+	pub async fn shutdown(self) {
+		self.ctx.shutdown();
+		let _ = self.worker.await;
+	}
+}
+
+struct MockPulseActor {
+	script: Vec<EntryUpdate>,
+	actions: Arc<Mutex<Vec<PulseAudioAction>>>,
+}
+
+#[async_trait]
+impl EventfulActor for MockPulseActor {
+	async fn start(&mut self, _ctx: Ctx) {}
+	async fn stop(&mut self) {}
+
+	fn handle_message<'a>(
+		&'a mut self,
+		ctx: Ctx,
+		msg: BoxedMessage,
+	) -> Pin<Box<dyn Future<Output = Result<()>> + Send + Sync + 'a>> {
+		Box::pin(async move {
+			if let Some(action) = msg.downcast_ref::<PulseAudioAction>() {
+				match action {
+					PulseAudioAction::RequestPulseAudioState => {
+						for update in &self.script {
+							ctx.send_to("event_loop", update.clone());
+						}
+					}
+					PulseAudioAction::Sync => {
+						ctx.send_to("event_loop", EntryUpdate::Synced);
+					}
+					_ => {}
+				};
+
+				self.actions.lock().unwrap().push(action.clone());
+			}
+
+			Ok(())
+		})
+	}
+}
+
+struct HeadlessEventLoop {
+	state: RSState,
+	snapshot: Arc<Mutex<Option<Snapshot>>>,
+}
+
+#[async_trait]
+impl EventfulActor for HeadlessEventLoop {
+	async fn start(&mut self, ctx: Ctx) {
+		self.state = RSState::new(ctx.clone());
+		self.state.ui.fixed_size = Some((SCREEN_WIDTH, SCREEN_HEIGHT));
+		self.state.redraw.resize = true;
+
+		ctx.send_to("pulseaudio", PulseAudioAction::RequestPulseAudioState);
+	}
+
+	async fn stop(&mut self) {}
+
+	fn handle_message<'a>(
+		&'a mut self,
+		ctx: Ctx,
+		msg: BoxedMessage,
+	) -> Pin<Box<dyn Future<Output = Result<()>> + Send + Sync + 'a>> {
+		Box::pin(async move {
+			action_handlers::handle(&msg, &mut self.state, &ctx)?;
+
+			if self.state.redraw.anything() {
+				ui::redraw(&mut io::sink(), &mut self.state).await?;
+
+				self.state.redraw.reset();
+			}
+
+			*self.snapshot.lock().unwrap() = Some(Snapshot::new(&self.state));
+
+			Ok(())
+		})
+	}
+}
@@ -0,0 +1,67 @@
+use std::time::{Duration, Instant};
+
+use pulse::volume::{ChannelVolumes, Volume};
+
+use super::entries::ident;
+use crate::{
+	entry::EntryType,
+	models::{History, PulseAudioAction},
+	util::percent_to_volume,
+};
+
+fn set_volume(percent: i16) -> PulseAudioAction {
+	let mut volume = ChannelVolumes::default();
+	volume.set(2, Volume(percent_to_volume(percent, 150)));
+
+	PulseAudioAction::SetVolume(ident(EntryType::Sink, 0), volume)
+}
+
+// one press of a volume key, from one percent to the other
+fn change(history: &mut History, from: i16, to: i16, now: Instant) {
+	history.record(set_volume(to), set_volume(from));
+	history.commit(now);
+}
+
+#[test]
+fn held_volume_key_is_undone_at_once() {
+	let start = Instant::now();
+	let at = |ms| start + Duration::from_millis(ms);
+
+	let mut history = History::default();
+	change(&mut history, 50, 55, at(0));
+	change(&mut history, 55, 60, at(500));
+	change(&mut history, 60, 65, at(530));
+
+	assert_eq!(history.undo(), Some(vec![set_volume(50)]));
+	assert_eq!(history.undo(), None);
+	assert_eq!(history.redo(), Some(vec![set_volume(65)]));
+}
+
+#[test]
+fn separate_volume_changes_are_undone_one_at_a_time() {
+	let start = Instant::now();
+	let at = |ms| start + Duration::from_millis(ms);
+
+	let mut history = History::default();
+	change(&mut history, 50, 55, at(0));
+	change(&mut history, 55, 60, at(5000));
+
+	assert_eq!(history.undo(), Some(vec![set_volume(55)]));
+	assert_eq!(history.undo(), Some(vec![set_volume(50)]));
+	assert_eq!(history.undo(), None);
+}
+
+#[test]
+fn redone_step_is_not_merged_into() {
+	let start = Instant::now();
+	let at = |ms| start + Duration::from_millis(ms);
+
+	let mut history = History::default();
+	change(&mut history, 50, 55, at(0));
+	history.undo();
+	history.redo();
+	change(&mut history, 55, 60, at(100));
+
+	assert_eq!(history.undo(), Some(vec![set_volume(55)]));
+	assert_eq!(history.undo(), Some(vec![set_volume(50)]));
+}
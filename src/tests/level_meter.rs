@@ -0,0 +1,72 @@
+use std::time::{Duration, Instant};
+
+use crate::models::{Ballistics, LevelMeter, Levels};
+
+fn ballistics() -> Ballistics {
+	Ballistics {
+		attack: Duration::from_millis(0),
+		decay: 20.0,
+		peak_hold: Duration::from_millis(1000),
+		floor: -60.0,
+	}
+}
+
+#[test]
+fn levels_are_peak_and_average_in_dbfs() {
+	let levels = Levels::from_samples(&[0.5, -1.0, 0.5, -0.5]);
+
+	assert!(levels.peak.abs() < 0.01);
+	// (0.5 * 3 + 1) / 4 = 0.625
+	assert!((levels.average + 4.08).abs() < 0.01);
+
+	let silence = Levels::from_samples(&[0.0, 0.0]);
+	assert_eq!(silence.peak, f32::NEG_INFINITY);
+	assert_eq!(Levels::from_samples(&[]), Levels::silence());
+}
+
+#[test]
+fn meter_decays_and_holds_its_peak() {
+	let ballistics = ballistics();
+	let start = Instant::now();
+	let at = |ms| start + Duration::from_millis(ms);
+
+	let mut meter = LevelMeter::default();
+	assert!(meter.update(
+		Levels {
+			peak: -6.0,
+			average: -12.0
+		},
+		at(0),
+		&ballistics
+	));
+	assert_eq!((meter.peak, meter.average, meter.hold), (-6.0, -12.0, -6.0));
+
+	// falls 20 dB per second, the marker stays put
+	meter.update(Levels::silence(), at(500), &ballistics);
+	assert!((meter.peak + 16.0).abs() < 0.01);
+	assert!((meter.average + 22.0).abs() < 0.01);
+	assert_eq!(meter.hold, -6.0);
+
+	// the marker starts falling after being held for a second
+	meter.update(Levels::silence(), at(1500), &ballistics);
+	assert!((meter.peak + 36.0).abs() < 0.01);
+	assert!((meter.hold + 16.0).abs() < 0.01);
+
+	// never below the floor
+	meter.update(Levels::silence(), at(10000), &ballistics);
+	assert_eq!(
+		(meter.peak, meter.average, meter.hold),
+		(-60.0, -60.0, -60.0)
+	);
+
+	// instant attack jumps to a louder block
+	meter.update(
+		Levels {
+			peak: -1.0,
+			average: -3.0,
+		},
+		at(10050),
+		&ballistics,
+	);
+	assert_eq!((meter.peak, meter.average, meter.hold), (-1.0, -3.0, -1.0));
+}
@@ -0,0 +1,43 @@
+mod cli;
+mod config;
+mod entries;
+mod events;
+mod fallback;
+mod filter;
+mod harness;
+mod history;
+mod level_meter;
+mod scenes;
+mod screen;
+mod state;
+mod volume;
+
+use std::{fs, path::Path};
+
+// compares text with src/tests/snapshots/<name>.txt, the file is written
+// instead if RSMIXER_UPDATE_SNAPSHOTS is set
+pub fn assert_snapshot(name: &str, text: &str) {
+	let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+		.join("src/tests/snapshots")
+		.join(format!("{}.txt", name));
+
+	if std::env::var_os("RSMIXER_UPDATE_SNAPSHOTS").is_some() {
+		fs::create_dir_all(path.parent().unwrap()).unwrap();
+		fs::write(&path, text).unwrap();
+		return;
+	}
+
+	let expected = fs::read_to_string(&path).unwrap_or_else(|_| {
+		panic!(
+			"snapshot {} is missing, run with RSMIXER_UPDATE_SNAPSHOTS=1 to create it\nactual:\n{}",
+			name, text
+		)
+	});
+	assert!(
+		expected == text,
+		"snapshot {} differs\nexpected:\n{}\nactual:\n{}",
+		name,
+		expected,
+		text
+	);
+}
@@ -0,0 +1,107 @@
+use pulse::volume::Volume;
+
+use super::entries::*;
+use crate::{
+	config::Scene, entry::EntryType, models::PulseAudioAction, util::percent_to_volume, VARIABLES,
+};
+
+#[test]
+fn scenes_restore_mute_routing_and_profiles() {
+	let scene = Scene::capture(&collect_entries(vec![
+		sink(0, "Speakers"),
+		sink(1, "Headphones"),
+		sink_input(5, "Firefox", 1),
+		card(2, "Built-in Audio", &["analog", "hdmi"], Some(0)),
+	]));
+
+	// indexes change after a restart, entries are matched by name
+	let mut entries = collect_entries(vec![
+		sink(3, "Speakers"),
+		sink(4, "Headphones"),
+		sink_input(7, "Firefox", 3),
+		card(8, "Built-in Audio", &["analog", "hdmi"], Some(1)),
+	]);
+	entries
+		.get_play_entry_mut(&ident(EntryType::Sink, 4))
+		.unwrap()
+		.mute = true;
+
+	let (actions, unmatched) = scene.restore(&entries);
+	assert_eq!(
+		actions,
+		vec![
+			PulseAudioAction::MuteEntry(ident(EntryType::Sink, 4), false),
+			PulseAudioAction::MoveEntryToParent(
+				ident(EntryType::SinkInput, 7),
+				ident(EntryType::Sink, 4)
+			),
+			PulseAudioAction::ChangeCardProfile(ident(EntryType::Card, 8), "analog".to_string()),
+		]
+	);
+	assert!(unmatched.is_empty());
+
+	// nothing to do once it's applied
+	let restored = collect_entries(vec![
+		sink(3, "Speakers"),
+		sink(4, "Headphones"),
+		sink_input(7, "Firefox", 4),
+		card(8, "Built-in Audio", &["analog", "hdmi"], Some(0)),
+	]);
+	assert_eq!(scene.restore(&restored), (Vec::new(), Vec::new()));
+}
+
+#[test]
+fn scenes_report_entries_that_are_gone() {
+	let scene = Scene::capture(&collect_entries(vec![
+		sink(0, "Speakers"),
+		sink(1, "Headphones"),
+		sink_input(5, "Firefox", 1),
+	]));
+
+	let (actions, unmatched) = scene.restore(&collect_entries(vec![
+		sink(0, "Speakers"),
+		sink_input(5, "Firefox", 0),
+	]));
+
+	assert!(actions.is_empty());
+	// the sink itself, and as the parent of Firefox
+	assert_eq!(unmatched, vec!["sink Headphones", "sink Headphones"]);
+}
+
+#[test]
+fn scene_volumes_are_clamped_to_the_maximum() {
+	// edited by hand, the second sink has a channel more than the real one
+	let scene: Scene = toml::from_str(
+		r#"
+		[[sinks]]
+		name = "Speakers"
+		volume = [60000, 65535]
+		mute = false
+
+		[[sinks]]
+		name = "Headphones"
+		volume = [65535, 65535, 65535]
+		mute = false
+		"#,
+	)
+	.unwrap();
+
+	let max_percent = VARIABLES.read().unwrap().max_volume_of(EntryType::Sink);
+	let mut max = volume();
+	max.set(
+		2,
+		Volume(percent_to_volume(max_percent as i16, max_percent)),
+	);
+
+	let (actions, _) = scene.restore(&collect_entries(vec![
+		sink(0, "Speakers"),
+		sink(1, "Headphones"),
+	]));
+	assert_eq!(
+		actions,
+		vec![
+			PulseAudioAction::SetVolume(ident(EntryType::Sink, 0), max),
+			PulseAudioAction::SetVolume(ident(EntryType::Sink, 1), max),
+		]
+	);
+}
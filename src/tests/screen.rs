@@ -0,0 +1,257 @@
+use super::{assert_snapshot, entries::*, harness::Harness};
+use crate::{
+	entry::EntryType,
+	models::{
+		context_menus::{ContextMenu, ContextMenuOption},
+		ConfigStatus, PageType, UIMode, UserAction, VirtualDevice,
+	},
+	ui::Rect,
+};
+
+#[tokio::test]
+async fn output_page() {
+	let harness = Harness::start(vec![
+		sink(0, "Speakers"),
+		sink(1, "Headphones"),
+		sink_input(5, "Firefox", 0),
+	]);
+
+	let snapshot = harness
+		.wait_for(|s| s.page_entries.len() == 3 && s.screen_contains("Headphones"))
+		.await;
+	assert_snapshot("output_page", &snapshot.text());
+
+	harness.shutdown().await;
+}
+
+#[tokio::test]
+async fn config_error_line() {
+	let harness = Harness::start(vec![sink(0, "Speakers"), sink_input(5, "Firefox", 0)]);
+	harness.wait_for(|s| s.page_entries.len() == 2).await;
+
+	harness.send(ConfigStatus::Error(
+		"'shift+x' is not a valid key".to_string(),
+	));
+
+	let snapshot = harness
+		.wait_for(|s| s.screen_contains("Config file error"))
+		.await;
+	assert!(snapshot.screen_contains("Firefox"));
+	assert_snapshot("config_error_line", &snapshot.text());
+
+	harness.shutdown().await;
+}
+
+#[tokio::test]
+async fn context_menu() {
+	let harness = Harness::start(vec![sink(0, "Speakers"), sink_input(5, "Firefox", 0)]);
+	harness.wait_for(|s| s.page_entries.len() == 2).await;
+
+	harness.send(UserAction::MoveDown(1));
+	harness.send(UserAction::OpenContextMenu(None));
+
+	let snapshot = harness
+		.wait_for(|s| s.ui_mode == UIMode::ContextMenu && s.screen_contains("Kill"))
+		.await;
+	assert_snapshot("context_menu", &snapshot.text());
+
+	harness.shutdown().await;
+}
+
+#[tokio::test]
+async fn details_panel() {
+	let harness = Harness::start(vec![
+		sink(0, "Speakers"),
+		with_details(
+			sink_input(5, "Firefox", 0),
+			&[
+				("Driver", "protocol-native.c"),
+				("Client", "12"),
+				("Client PID", "4242"),
+				("Sample spec", "float32le 2ch 44100Hz"),
+				("Resample method", "speex-float-1"),
+			],
+			&[
+				("application.name", "Firefox"),
+				("application.process.id", "4242"),
+				("media.name", "AudioStream"),
+			],
+		),
+	]);
+	harness.wait_for(|s| s.page_entries.len() == 2).await;
+
+	harness.send(UserAction::MoveDown(1));
+	harness.send(UserAction::OpenContextMenu(None));
+	harness
+		.wait_for(|s| s.ui_mode == UIMode::ContextMenu && s.screen_contains("Details"))
+		.await;
+
+	// move, kill, input exact volume, details
+	harness.send(UserAction::MoveDown(3));
+	harness.send(UserAction::Confirm);
+
+	let snapshot = harness
+		.wait_for(|s| s.ui_mode == UIMode::Details && s.screen_contains("media.name"))
+		.await;
+	assert_snapshot("details_panel", &snapshot.text());
+
+	harness.shutdown().await;
+}
+
+#[tokio::test]
+async fn client_groups() {
+	let harness = Harness::start(vec![
+		sink(0, "Speakers"),
+		client(3, "Firefox"),
+		client(4, "Music player"),
+		with_client(sink_input(5, "YouTube", 0), 3),
+		with_client(sink_input(6, "Spotify", 0), 4),
+		with_client(sink_input(7, "Meet", 0), 3),
+	]);
+
+	// the music player has a single stream, so it isn't grouped
+	let snapshot = harness
+		.wait_for(|s| s.page_entries.len() == 5 && s.screen_contains("Meet"))
+		.await;
+	assert_snapshot("client_groups", &snapshot.text());
+
+	harness.shutdown().await;
+}
+
+#[tokio::test]
+async fn cards_page() {
+	let harness = Harness::start(vec![
+		sink(0, "Speakers"),
+		card(0, "Built-in Audio", &["off", "analog-stereo"], Some(1)),
+	]);
+	harness.wait_for(|s| s.page_entries.len() == 1).await;
+
+	harness.send(UserAction::ChangePage(PageType::Cards));
+
+	let snapshot = harness
+		.wait_for(|s| {
+			s.selected == Some(ident(EntryType::Card, 0)) && s.screen_contains("Built-in")
+		})
+		.await;
+	assert_snapshot("cards_page", &snapshot.text());
+
+	harness.shutdown().await;
+}
+
+#[tokio::test]
+async fn modules_page() {
+	let harness = Harness::start(vec![
+		sink(0, "Speakers"),
+		module(0, "module-device-restore", ""),
+		module(
+			7,
+			"module-null-sink",
+			"sink_name=virtual sink_properties=device.description=Virtual",
+		),
+	]);
+	harness.wait_for(|s| s.page_entries.len() == 1).await;
+
+	harness.send(UserAction::ChangePage(PageType::Modules));
+
+	let snapshot = harness
+		.wait_for(|s| s.page_entries.len() == 2 && s.screen_contains("module-null-sink"))
+		.await;
+	assert_snapshot("modules_page", &snapshot.text());
+
+	harness.shutdown().await;
+}
+
+#[tokio::test]
+async fn combine_picking() {
+	let harness = Harness::start(vec![
+		sink(0, "Speakers"),
+		sink(1, "Headphones"),
+		sink_input(5, "Firefox", 0),
+	]);
+	harness.wait_for(|s| s.page_entries.len() == 3).await;
+
+	harness.send(UserAction::StartPicking(VirtualDevice::Combine));
+
+	let snapshot = harness
+		.wait_for(|s| s.page_entries.len() == 2 && s.screen_contains("(picked)"))
+		.await;
+	assert_snapshot("combine_picking", &snapshot.text());
+
+	harness.shutdown().await;
+}
+
+#[tokio::test]
+async fn card_profile_menu() {
+	let harness = Harness::start(vec![card_with_devices(
+		0,
+		"Built-in Audio",
+		&[
+			("Analog Stereo Duplex", 1, 1),
+			("Digital Surround 5.1", 1, 0),
+			("Off", 0, 0),
+		],
+		Some(0),
+	)]);
+
+	harness.send(UserAction::ChangePage(PageType::Cards));
+	harness
+		.wait_for(|s| s.selected == Some(ident(EntryType::Card, 0)))
+		.await;
+	harness.send(UserAction::OpenContextMenu(None));
+
+	let snapshot = harness
+		.wait_for(|s| s.ui_mode == UIMode::ContextMenu && s.screen_contains("(active)"))
+		.await;
+	assert_snapshot("card_profile_menu", &snapshot.text());
+
+	harness.shutdown().await;
+}
+
+#[test]
+fn context_menu_scrolls_only_past_the_width() {
+	let mut menu = ContextMenu::default();
+	menu.options = vec![ContextMenuOption::Kill];
+
+	menu.area = Rect::new(0, 0, 4, 3);
+	assert_eq!(menu.max_horizontal_scroll(), 0);
+
+	menu.area = Rect::new(0, 0, 3, 3);
+	assert_eq!(menu.max_horizontal_scroll(), 1);
+
+	menu.area = Rect::new(0, 0, 2, 3);
+	assert_eq!(menu.max_horizontal_scroll(), 1);
+}
+
+#[tokio::test]
+async fn level_meter() {
+	let harness = Harness::start(vec![
+		sink(0, "Speakers"),
+		sink(1, "Headphones"),
+		levels(EntryType::Sink, 0, -12.0, -30.0),
+		levels(EntryType::Sink, 1, -3.0, -6.0),
+	]);
+
+	let snapshot = harness
+		.wait_for(|s| s.page_entries.len() == 2 && s.text().matches('▯').count() > 10)
+		.await;
+	assert_snapshot("level_meter", &snapshot.text());
+
+	harness.shutdown().await;
+}
+
+#[tokio::test]
+async fn channel_meters() {
+	let harness = Harness::start(vec![
+		sink(0, "Speakers"),
+		sink_input(5, "Firefox", 0),
+		channel_levels(EntryType::Sink, 0, &[(-6.0, -12.0), (-60.0, -60.0)]),
+		channel_levels(EntryType::SinkInput, 5, &[(-3.0, -9.0), (-30.0, -40.0)]),
+	]);
+
+	let snapshot = harness
+		.wait_for(|s| s.page_entries.len() == 2 && s.text().matches("FR").count() == 2)
+		.await;
+	assert_snapshot("channel_meters", &snapshot.text());
+
+	harness.shutdown().await;
+}
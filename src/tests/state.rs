@@ -0,0 +1,918 @@
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+use pulse::volume::{Volume, VolumeDB};
+
+use super::{entries::*, harness::Harness};
+use crate::{
+	config::Rule,
+	entry::EntryType,
+	models::{
+		EntryUpdate, PageType, PulseAudioAction, UIMode, UserAction, UserInput, VirtualDevice,
+	},
+	util::percent_to_volume,
+};
+
+fn script() -> Vec<crate::models::EntryUpdate> {
+	vec![
+		sink(0, "Speakers"),
+		sink(1, "Headphones"),
+		sink_input(5, "Firefox", 0),
+		sink_input(6, "Music player", 0),
+	]
+}
+
+#[tokio::test]
+async fn shows_parents_with_their_children() {
+	let harness = Harness::start(script());
+
+	harness
+		.wait_for(|s| {
+			s.page_entries
+				== vec![
+					ident(EntryType::Sink, 0),
+					ident(EntryType::SinkInput, 5),
+					ident(EntryType::SinkInput, 6),
+					ident(EntryType::Sink, 1),
+				]
+		})
+		.await;
+
+	harness.shutdown().await;
+}
+
+#[tokio::test]
+async fn move_down_changes_the_target_of_actions() {
+	let harness = Harness::start(script());
+	harness.wait_for(|s| s.page_entries.len() == 4).await;
+
+	harness.send(UserAction::MoveDown(2));
+	harness
+		.wait_for(|s| s.selected == Some(ident(EntryType::SinkInput, 6)))
+		.await;
+
+	harness.send(UserAction::RequestMute(None));
+
+	assert_eq!(
+		harness.wait_for_actions(1).await,
+		vec![PulseAudioAction::MuteEntry(
+			ident(EntryType::SinkInput, 6),
+			true
+		)]
+	);
+
+	harness.shutdown().await;
+}
+
+#[tokio::test]
+async fn move_down_wraps_around_the_page() {
+	let harness = Harness::start(script());
+	harness.wait_for(|s| s.page_entries.len() == 4).await;
+
+	harness.send(UserAction::MoveDown(5));
+
+	harness
+		.wait_for(|s| s.selected == Some(ident(EntryType::SinkInput, 5)))
+		.await;
+
+	harness.shutdown().await;
+}
+
+#[tokio::test]
+async fn update_entry_adds_new_children_under_their_parent() {
+	let harness = Harness::start(script());
+	harness.wait_for(|s| s.page_entries.len() == 4).await;
+
+	harness.send(sink_input(7, "Game", 1));
+
+	harness
+		.wait_for(|s| s.page_entries.last() == Some(&ident(EntryType::SinkInput, 7)))
+		.await;
+
+	// doesn't fit on the screen until it's selected
+	harness.send(UserAction::MoveUp(1));
+	harness.wait_for(|s| s.screen_contains("Game")).await;
+
+	harness.shutdown().await;
+}
+
+#[tokio::test]
+async fn remove_entry_keeps_selection_on_the_page() {
+	let harness = Harness::start(script());
+	harness.wait_for(|s| s.page_entries.len() == 4).await;
+
+	harness.send(UserAction::MoveDown(3));
+	harness
+		.wait_for(|s| s.selected == Some(ident(EntryType::Sink, 1)))
+		.await;
+
+	harness.send(removed(EntryType::Sink, 1));
+
+	let snapshot = harness.wait_for(|s| s.page_entries.len() == 3).await;
+	assert_eq!(snapshot.selected, Some(ident(EntryType::SinkInput, 6)));
+	// only the toast of the removal still names it
+	assert!(snapshot.screen_contains("Sink 'Headphones' removed"));
+	assert_eq!(snapshot.text().matches("Headphones").count(), 1);
+
+	harness.shutdown().await;
+}
+
+#[tokio::test]
+async fn move_entry_mode_moves_entry_to_the_chosen_parent() {
+	let harness = Harness::start(script());
+	harness.wait_for(|s| s.page_entries.len() == 4).await;
+
+	harness.send(UserAction::MoveDown(1));
+	harness.send(UserAction::OpenContextMenu(None));
+	harness.wait_for(|s| s.ui_mode == UIMode::ContextMenu).await;
+
+	// the first option of a sink input is "Move"
+	harness.send(UserAction::Confirm);
+	harness
+		.wait_for(|s| {
+			s.ui_mode
+				== UIMode::MoveEntry(ident(EntryType::SinkInput, 5), ident(EntryType::Sink, 0))
+		})
+		.await;
+
+	harness.send(UserAction::MoveDown(1));
+	harness
+		.wait_for(|s| {
+			s.ui_mode
+				== UIMode::MoveEntry(ident(EntryType::SinkInput, 5), ident(EntryType::Sink, 1))
+		})
+		.await;
+
+	harness.send(UserAction::Confirm);
+
+	assert_eq!(
+		harness.wait_for_actions(1).await,
+		vec![PulseAudioAction::MoveEntryToParent(
+			ident(EntryType::SinkInput, 5),
+			ident(EntryType::Sink, 1)
+		)]
+	);
+	harness.wait_for(|s| s.ui_mode == UIMode::Normal).await;
+
+	harness.shutdown().await;
+}
+
+#[tokio::test]
+async fn change_page_shows_cards() {
+	let mut script = script();
+	script.push(card(
+		0,
+		"Built-in Audio",
+		&["off", "analog-stereo"],
+		Some(1),
+	));
+	let harness = Harness::start(script);
+	harness.wait_for(|s| s.page_entries.len() == 4).await;
+
+	harness.send(UserAction::ChangePage(PageType::Cards));
+
+	harness
+		.wait_for(|s| s.page_entries == vec![ident(EntryType::Card, 0)])
+		.await;
+
+	harness.shutdown().await;
+}
+
+fn modules_script() -> Vec<crate::models::EntryUpdate> {
+	let mut script = script();
+	script.push(module(0, "module-device-restore", ""));
+	script.push(module(
+		7,
+		"module-null-sink",
+		"sink_name=virtual sink_properties=device.description=Virtual",
+	));
+	script
+}
+
+#[tokio::test]
+async fn context_menu_unloads_modules() {
+	let harness = Harness::start(modules_script());
+	harness.wait_for(|s| s.page_entries.len() == 4).await;
+
+	harness.send(UserAction::ChangePage(PageType::Modules));
+	harness.wait_for(|s| s.page_entries.len() == 2).await;
+
+	harness.send(UserAction::MoveDown(1));
+	harness.send(UserAction::OpenContextMenu(None));
+	harness.wait_for(|s| s.ui_mode == UIMode::ContextMenu).await;
+
+	// the first option of a module is "Unload"
+	harness.send(UserAction::Confirm);
+
+	assert_eq!(
+		harness.wait_for_actions(1).await,
+		vec![PulseAudioAction::UnloadModule(ident(EntryType::Module, 7))]
+	);
+
+	harness.shutdown().await;
+}
+
+#[tokio::test]
+async fn load_module_splits_name_and_arguments() {
+	let harness = Harness::start(modules_script());
+	harness.wait_for(|s| s.page_entries.len() == 4).await;
+
+	harness.send(UserAction::ChangePage(PageType::Modules));
+	harness.send(UserAction::LoadModule);
+	harness.wait_for(|s| s.ui_mode == UIMode::LoadModule).await;
+
+	// every key is applied to the value the event loop had when it was pressed
+	let mut typed = String::new();
+	for c in "module-loopback  latency_msec=20".chars() {
+		harness.send(UserInput::new(Event::Key(KeyEvent::new(
+			KeyCode::Char(c),
+			KeyModifiers::NONE,
+		))));
+		typed.push(c);
+		harness.wait_for(|s| s.module_input == typed).await;
+	}
+
+	assert!(harness
+		.wait_for(|_| true)
+		.await
+		.screen_contains("module-loopback  latency_msec=20"));
+
+	harness.send(UserAction::Confirm);
+
+	assert_eq!(
+		harness.wait_for_actions(1).await,
+		vec![PulseAudioAction::LoadModule(
+			"module-loopback".to_string(),
+			"latency_msec=20".to_string()
+		)]
+	);
+	harness.wait_for(|s| s.ui_mode == UIMode::Normal).await;
+
+	harness.shutdown().await;
+}
+
+#[tokio::test]
+async fn removed_module_disappears_from_the_page() {
+	let harness = Harness::start(modules_script());
+	harness.wait_for(|s| s.page_entries.len() == 4).await;
+
+	harness.send(UserAction::ChangePage(PageType::Modules));
+	harness.wait_for(|s| s.page_entries.len() == 2).await;
+
+	harness.send(removed(EntryType::Module, 7));
+
+	let snapshot = harness
+		.wait_for(|s| s.page_entries == vec![ident(EntryType::Module, 0)])
+		.await;
+	assert!(!snapshot.screen_contains("module-null-sink"));
+
+	harness.shutdown().await;
+}
+
+fn virtual_devices_script() -> Vec<crate::models::EntryUpdate> {
+	let mut script = script();
+	script.push(source(3, "Microphone"));
+	script
+}
+
+#[tokio::test]
+async fn null_sink_is_named_in_a_dialog() {
+	let harness = Harness::start(virtual_devices_script());
+	harness.wait_for(|s| s.page_entries.len() == 4).await;
+
+	harness.send(UserAction::CreateNullSink);
+	harness
+		.wait_for(|s| s.ui_mode == UIMode::NameNullSink)
+		.await;
+
+	let mut typed = String::new();
+	for c in "Game chat".chars() {
+		harness.send(UserInput::new(Event::Key(KeyEvent::new(
+			KeyCode::Char(c),
+			KeyModifiers::NONE,
+		))));
+		typed.push(c);
+		harness.wait_for(|s| s.module_input == typed).await;
+	}
+
+	harness.send(UserAction::Confirm);
+
+	assert_eq!(
+		harness.wait_for_actions(1).await,
+		vec![PulseAudioAction::LoadModule(
+			"module-null-sink".to_string(),
+			"sink_name=Game_chat sink_properties='device.description=\"Game chat\"'".to_string()
+		)]
+	);
+
+	harness.shutdown().await;
+}
+
+#[tokio::test]
+async fn loopback_picks_a_source() {
+	let harness = Harness::start(virtual_devices_script());
+	harness.wait_for(|s| s.page_entries.len() == 4).await;
+
+	harness.send(UserAction::StartPicking(VirtualDevice::Loopback(ident(
+		EntryType::Sink,
+		1,
+	))));
+	harness
+		.wait_for(|s| s.page_entries == vec![ident(EntryType::Source, 3)])
+		.await;
+
+	harness.send(UserAction::Confirm);
+
+	assert_eq!(
+		harness.wait_for_actions(1).await,
+		vec![PulseAudioAction::LoadModule(
+			"module-loopback".to_string(),
+			"source=3 sink=1".to_string()
+		)]
+	);
+	harness.wait_for(|s| s.page_entries.len() == 4).await;
+
+	harness.shutdown().await;
+}
+
+#[tokio::test]
+async fn combine_starts_with_the_selected_sink() {
+	let harness = Harness::start(virtual_devices_script());
+	harness.wait_for(|s| s.page_entries.len() == 4).await;
+
+	harness.send(UserAction::StartPicking(VirtualDevice::Combine));
+	harness
+		.wait_for(|s| s.page_entries == vec![ident(EntryType::Sink, 0), ident(EntryType::Sink, 1)])
+		.await;
+
+	// a single sink isn't enough
+	harness.send(UserAction::Confirm);
+	harness.wait_for(|s| s.ui_mode == UIMode::Message).await;
+	harness.send(UserAction::CloseContextMenu);
+
+	harness.send(UserAction::StartPicking(VirtualDevice::Combine));
+	harness.send(UserAction::MoveDown(1));
+	harness.send(UserAction::TogglePicked);
+	harness.send(UserAction::Confirm);
+
+	assert_eq!(
+		harness.wait_for_actions(1).await,
+		vec![PulseAudioAction::LoadModule(
+			"module-combine-sink".to_string(),
+			"slaves=0,1".to_string()
+		)]
+	);
+
+	harness.shutdown().await;
+}
+
+#[tokio::test]
+async fn created_modules_are_unloaded_newest_first() {
+	let harness = Harness::start(modules_script());
+	harness.wait_for(|s| s.page_entries.len() == 4).await;
+
+	harness.send(EntryUpdate::ModuleLoaded(ident(EntryType::Module, 0)));
+	harness.send(EntryUpdate::ModuleLoaded(ident(EntryType::Module, 7)));
+	harness.send(removed(EntryType::Module, 0));
+	harness.send(EntryUpdate::ModuleLoaded(ident(EntryType::Module, 8)));
+	harness.send(UserAction::UnloadCreatedModules);
+
+	assert_eq!(
+		harness.wait_for_actions(2).await,
+		vec![
+			PulseAudioAction::UnloadModule(ident(EntryType::Module, 8)),
+			PulseAudioAction::UnloadModule(ident(EntryType::Module, 7)),
+		]
+	);
+
+	harness.shutdown().await;
+}
+
+#[tokio::test]
+async fn context_menu_changes_port() {
+	let harness = Harness::start(vec![
+		with_ports(
+			sink(0, "Built-in Audio"),
+			&[("Line Out", true), ("Headphone Jack", false)],
+			Some(0),
+		),
+		with_ports(sink(1, "USB Audio"), &[("Analog Output", true)], Some(0)),
+	]);
+	harness.wait_for(|s| s.page_entries.len() == 2).await;
+
+	// a single port can't be changed
+	harness.send(UserAction::MoveDown(1));
+	harness.send(UserAction::OpenContextMenu(None));
+	let snapshot = harness
+		.wait_for(|s| s.ui_mode == UIMode::ContextMenu && s.screen_contains("Suspend"))
+		.await;
+	assert!(!snapshot.screen_contains("Change port"));
+	harness.send(UserAction::CloseContextMenu);
+
+	harness.send(UserAction::MoveUp(1));
+	harness.send(UserAction::OpenContextMenu(None));
+	harness
+		.wait_for(|s| s.ui_mode == UIMode::ContextMenu && s.screen_contains("Change port"))
+		.await;
+
+	// suspend, set as default, input exact volume, details, change port
+	harness.send(UserAction::MoveDown(4));
+	harness.send(UserAction::Confirm);
+	harness
+		.wait_for(|s| s.ui_mode == UIMode::ContextMenu && s.screen_contains("Headphone Jack"))
+		.await;
+
+	// the active port is selected first
+	harness.send(UserAction::MoveDown(1));
+	harness.send(UserAction::Confirm);
+
+	assert_eq!(
+		harness.wait_for_actions(1).await,
+		vec![PulseAudioAction::SetPort(
+			ident(EntryType::Sink, 0),
+			"Headphone Jack".to_string()
+		)]
+	);
+
+	harness.shutdown().await;
+}
+
+#[tokio::test]
+async fn details_follow_their_entry() {
+	let harness = Harness::start(vec![
+		with_details(sink(0, "Speakers"), &[("Driver", "alsa")], &[]),
+		sink(1, "Headphones"),
+	]);
+	harness.wait_for(|s| s.page_entries.len() == 2).await;
+
+	harness.send(UserAction::ShowDetails);
+	harness
+		.wait_for(|s| s.ui_mode == UIMode::Details && s.screen_contains("alsa"))
+		.await;
+
+	// updates of other entries don't change the panel
+	harness.send(with_details(
+		sink(1, "Headphones"),
+		&[("Driver", "bluez")],
+		&[],
+	));
+	harness.send(with_details(
+		sink(0, "Speakers"),
+		&[("Driver", "alsa")],
+		&[("device.bus", "pci")],
+	));
+	let snapshot = harness
+		.wait_for(|s| s.ui_mode == UIMode::Details && s.screen_contains("device.bus"))
+		.await;
+	assert!(!snapshot.screen_contains("bluez"));
+
+	// removing the entry closes its details
+	harness.send(removed(EntryType::Sink, 0));
+	harness
+		.wait_for(|s| s.ui_mode == UIMode::Normal && s.page_entries.len() == 1)
+		.await;
+
+	harness.shutdown().await;
+}
+
+#[tokio::test]
+async fn client_groups_change_all_their_streams() {
+	let harness = Harness::start(vec![
+		sink(0, "Speakers"),
+		sink(1, "Headphones"),
+		client(3, "Firefox"),
+		with_client(sink_input(5, "YouTube", 0), 3),
+		with_client(sink_input(7, "Meet", 0), 3),
+		with_client(sink_input(8, "Notification", 1), 3),
+	]);
+	harness
+		.wait_for(|s| {
+			s.page_entries
+				== vec![
+					ident(EntryType::Sink, 0),
+					ident(EntryType::Client, 3),
+					ident(EntryType::SinkInput, 5),
+					ident(EntryType::SinkInput, 7),
+					ident(EntryType::Sink, 1),
+					ident(EntryType::SinkInput, 8),
+				]
+		})
+		.await;
+
+	harness.send(UserAction::MoveDown(1));
+	harness
+		.wait_for(|s| s.selected == Some(ident(EntryType::Client, 3)))
+		.await;
+
+	// the stream on the other sink isn't a part of the group
+	harness.send(UserAction::RequestMute(None));
+	assert_eq!(
+		harness.wait_for_actions(2).await,
+		vec![
+			PulseAudioAction::MuteEntry(ident(EntryType::SinkInput, 5), true),
+			PulseAudioAction::MuteEntry(ident(EntryType::SinkInput, 7), true),
+		]
+	);
+
+	harness.send(UserAction::OpenContextMenu(None));
+	harness.wait_for(|s| s.ui_mode == UIMode::ContextMenu).await;
+	harness.send(UserAction::Confirm);
+	harness
+		.wait_for(|s| {
+			s.ui_mode == UIMode::MoveEntry(ident(EntryType::Client, 3), ident(EntryType::Sink, 0))
+		})
+		.await;
+	harness.send(UserAction::MoveDown(1));
+	harness
+		.wait_for(|s| {
+			s.ui_mode == UIMode::MoveEntry(ident(EntryType::Client, 3), ident(EntryType::Sink, 1))
+		})
+		.await;
+	harness.send(UserAction::Confirm);
+
+	assert_eq!(
+		harness.wait_for_actions(4).await[2..],
+		[
+			PulseAudioAction::MoveEntryToParent(
+				ident(EntryType::SinkInput, 5),
+				ident(EntryType::Sink, 1)
+			),
+			PulseAudioAction::MoveEntryToParent(
+				ident(EntryType::SinkInput, 7),
+				ident(EntryType::Sink, 1)
+			),
+		]
+	);
+
+	harness.shutdown().await;
+}
+
+#[tokio::test]
+async fn exact_volume_accepts_decibels() {
+	let harness = Harness::start(script());
+	harness.wait_for(|s| s.page_entries.len() == 4).await;
+
+	harness.send(UserAction::InputVolumeValue);
+	harness
+		.wait_for(|s| s.ui_mode == UIMode::InputVolumeValue)
+		.await;
+
+	harness.send(UserAction::ChangeVolumeInputValue("-6dB".to_string(), 4));
+	harness.send(UserAction::Confirm);
+
+	let expected = Volume::from(VolumeDB(-6.0));
+	let mut volume = volume();
+	volume.set(2, expected);
+
+	assert_eq!(
+		harness.wait_for_actions(1).await,
+		vec![PulseAudioAction::SetVolume(
+			ident(EntryType::Sink, 0),
+			volume
+		)]
+	);
+
+	harness.shutdown().await;
+}
+
+#[tokio::test]
+async fn exact_volume_accepts_typed_minus_infinity() {
+	let harness = Harness::start(script());
+	harness.wait_for(|s| s.page_entries.len() == 4).await;
+
+	harness.send(UserAction::InputVolumeValue);
+	harness
+		.wait_for(|s| s.ui_mode == UIMode::InputVolumeValue)
+		.await;
+	harness.send(UserAction::ChangeVolumeInputValue(String::new(), 0));
+	harness.wait_for(|s| s.volume_input.is_empty()).await;
+
+	let mut typed = String::new();
+	for c in "-inf dB".chars() {
+		harness.send(UserInput::new(Event::Key(KeyEvent::new(
+			KeyCode::Char(c),
+			KeyModifiers::NONE,
+		))));
+		typed.push(c);
+		harness.wait_for(|s| s.volume_input == typed).await;
+	}
+
+	harness.send(UserAction::Confirm);
+
+	let mut volume = volume();
+	volume.set(2, Volume::MUTED);
+
+	assert_eq!(
+		harness.wait_for_actions(1).await,
+		vec![PulseAudioAction::SetVolume(
+			ident(EntryType::Sink, 0),
+			volume
+		)]
+	);
+
+	harness.shutdown().await;
+}
+
+#[tokio::test]
+async fn unparsable_exact_volume_is_reported() {
+	let harness = Harness::start(script());
+	harness.wait_for(|s| s.page_entries.len() == 4).await;
+
+	harness.send(UserAction::InputVolumeValue);
+	harness
+		.wait_for(|s| s.ui_mode == UIMode::InputVolumeValue)
+		.await;
+
+	harness.send(UserAction::ChangeVolumeInputValue("loud".to_string(), 4));
+	harness.wait_for(|s| s.volume_input == "loud").await;
+	harness.send(UserAction::Confirm);
+
+	harness
+		.wait_for(|s| s.ui_mode == UIMode::Normal && s.screen_contains("'loud' is not a volume"))
+		.await;
+	assert!(harness.actions().is_empty());
+
+	harness.shutdown().await;
+}
+
+#[tokio::test]
+async fn channel_lock_only_toggles_in_the_channel_view() {
+	let harness = Harness::start(script());
+	harness.wait_for(|s| s.page_entries.len() == 4).await;
+
+	// does nothing outside of the channel view
+	harness.send(UserAction::ToggleChannelLock);
+	harness.send(UserAction::ShowChannels);
+	harness
+		.wait_for(|s| s.ui_mode == UIMode::Channels && s.screen_contains("channels unlocked"))
+		.await;
+
+	harness.send(UserAction::ToggleChannelLock);
+	harness
+		.wait_for(|s| s.screen_contains("channels locked"))
+		.await;
+
+	harness.shutdown().await;
+}
+
+#[tokio::test]
+async fn marked_entries_change_together() {
+	let harness = Harness::start(script());
+	harness.wait_for(|s| s.page_entries.len() == 4).await;
+
+	harness.send(UserAction::MoveDown(1));
+	harness.send(UserAction::ToggleMarked);
+	harness.send(UserAction::MoveDown(1));
+	harness.send(UserAction::ToggleMarked);
+	harness.send(UserAction::MoveDown(1));
+	harness
+		.wait_for(|s| {
+			s.selected == Some(ident(EntryType::Sink, 1))
+				&& s.screen_contains("Firefox (marked)")
+				&& s.screen_contains("Music player (marked)")
+		})
+		.await;
+
+	// the marked entries, not the selected one
+	harness.send(UserAction::RequestMute(None));
+	assert_eq!(
+		harness.wait_for_actions(2).await,
+		vec![
+			PulseAudioAction::MuteEntry(ident(EntryType::SinkInput, 5), true),
+			PulseAudioAction::MuteEntry(ident(EntryType::SinkInput, 6), true),
+		]
+	);
+
+	harness.send(UserAction::CloseContextMenu);
+	harness.wait_for(|s| !s.screen_contains("(marked)")).await;
+
+	harness.send(UserAction::RequestMute(None));
+	assert_eq!(
+		harness.wait_for_actions(3).await[2..],
+		[PulseAudioAction::MuteEntry(ident(EntryType::Sink, 1), true)]
+	);
+
+	harness.shutdown().await;
+}
+
+#[tokio::test]
+async fn kill_applies_to_marked_entries() {
+	let harness = Harness::start(script());
+	harness.wait_for(|s| s.page_entries.len() == 4).await;
+
+	harness.send(UserAction::MoveDown(1));
+	harness.send(UserAction::ToggleMarked);
+	harness.send(UserAction::MoveDown(1));
+	harness.send(UserAction::ToggleMarked);
+	harness
+		.wait_for(|s| s.screen_contains("Music player (marked)"))
+		.await;
+
+	harness.send(UserAction::OpenContextMenu(None));
+	harness.wait_for(|s| s.ui_mode == UIMode::ContextMenu).await;
+	// "Kill" is the second option of a sink input
+	harness.send(UserAction::MoveDown(1));
+	harness.send(UserAction::Confirm);
+
+	assert_eq!(
+		harness.wait_for_actions(2).await,
+		vec![
+			PulseAudioAction::KillEntry(ident(EntryType::SinkInput, 5)),
+			PulseAudioAction::KillEntry(ident(EntryType::SinkInput, 6)),
+		]
+	);
+
+	harness.shutdown().await;
+}
+
+#[tokio::test]
+async fn undo_restores_what_an_action_changed() {
+	let harness = Harness::start(script());
+	harness.wait_for(|s| s.page_entries.len() == 4).await;
+
+	harness.send(UserAction::RequstChangeVolume(20, None));
+	let raised = harness.wait_for_actions(1).await[0].clone();
+
+	harness.send(UserAction::Undo);
+	assert_eq!(
+		harness.wait_for_actions(2).await[1..],
+		[PulseAudioAction::SetVolume(
+			ident(EntryType::Sink, 0),
+			volume()
+		)]
+	);
+
+	harness.send(UserAction::Redo);
+	assert_eq!(harness.wait_for_actions(3).await[2..], [raised]);
+
+	// nothing left to redo
+	harness.send(UserAction::Redo);
+	harness.send(UserAction::MoveDown(1));
+	harness
+		.wait_for(|s| s.selected == Some(ident(EntryType::SinkInput, 5)))
+		.await;
+	assert_eq!(harness.actions().len(), 3);
+
+	harness.shutdown().await;
+}
+
+#[tokio::test]
+async fn undo_moves_a_stream_back() {
+	let harness = Harness::start(script());
+	harness.wait_for(|s| s.page_entries.len() == 4).await;
+
+	harness.send(UserAction::MoveDown(1));
+	harness.send(UserAction::OpenContextMenu(None));
+	harness.wait_for(|s| s.ui_mode == UIMode::ContextMenu).await;
+	harness.send(UserAction::Confirm);
+	harness
+		.wait_for(|s| matches!(s.ui_mode, UIMode::MoveEntry(_, _)))
+		.await;
+	harness.send(UserAction::MoveDown(1));
+	harness
+		.wait_for(|s| {
+			s.ui_mode
+				== UIMode::MoveEntry(ident(EntryType::SinkInput, 5), ident(EntryType::Sink, 1))
+		})
+		.await;
+	harness.send(UserAction::Confirm);
+	harness.wait_for(|s| s.ui_mode == UIMode::Normal).await;
+
+	harness.send(UserAction::Undo);
+	assert_eq!(
+		harness.wait_for_actions(2).await[1..],
+		[PulseAudioAction::MoveEntryToParent(
+			ident(EntryType::SinkInput, 5),
+			ident(EntryType::Sink, 0)
+		)]
+	);
+
+	harness.shutdown().await;
+}
+
+#[tokio::test]
+async fn set_as_default_uses_the_device_name() {
+	let harness = Harness::start(vec![
+		with_device_name(sink(0, "Speakers"), "alsa_output.speakers"),
+		with_device_name(sink(1, "Headphones"), "alsa_output.headphones"),
+		EntryUpdate::DefaultChanged(ident(EntryType::Sink, 0)),
+	]);
+	harness.wait_for(|s| s.page_entries.len() == 2).await;
+
+	harness.send(UserAction::MoveDown(1));
+	harness.send(UserAction::OpenContextMenu(None));
+	harness.wait_for(|s| s.ui_mode == UIMode::ContextMenu).await;
+	// suspend, set as default
+	harness.send(UserAction::MoveDown(1));
+	harness.send(UserAction::Confirm);
+	assert_eq!(
+		harness.wait_for_actions(1).await,
+		vec![PulseAudioAction::SetDefault(
+			ident(EntryType::Sink, 1),
+			"alsa_output.headphones".to_string()
+		)]
+	);
+
+	harness.send(UserAction::Undo);
+	assert_eq!(
+		harness.wait_for_actions(2).await[1..],
+		[PulseAudioAction::SetDefault(
+			ident(EntryType::Sink, 0),
+			"alsa_output.speakers".to_string()
+		)]
+	);
+
+	harness.shutdown().await;
+}
+
+fn rule(toml: &str) -> Rule {
+	toml::from_str(toml).unwrap()
+}
+
+#[test]
+fn rules_match_by_application_and_properties() {
+	let properties = [("media.role", "phone"), ("application.name", "Skype")]
+		.iter()
+		.map(|(k, v)| (k.to_string(), v.to_string()))
+		.collect();
+
+	assert!(rule(r#"application = "skype""#).matches("Skype", &properties));
+	assert!(!rule(r#"application = "Skype""#).matches("Firefox", &properties));
+
+	let by_role = rule(r#"properties = { "media.role" = "phone" }"#);
+	assert!(by_role.matches("Skype", &properties));
+	assert!(!by_role.matches("Skype", &Default::default()));
+
+	// both have to match
+	let both = r#"
+		application = "Firefox"
+		properties = { "media.role" = "phone" }
+	"#;
+	assert!(!rule(both).matches("Skype", &properties));
+
+	// a rule without either matches nothing
+	assert!(!rule("mute = true").matches("Skype", &properties));
+}
+
+#[test]
+fn rules_set_volume_mute_and_target() {
+	let entries = collect_entries(vec![
+		with_device_name(sink(0, "HDMI"), "alsa_output.pci-hdmi"),
+		with_device_name(sink(1, "HDMI"), "alsa_output.usb-hdmi"),
+		sink_input(5, "Firefox", 0),
+	]);
+	let firefox = entries.get(&ident(EntryType::SinkInput, 5)).unwrap();
+
+	let mut forty = volume();
+	forty.set(2, Volume(percent_to_volume(40, 150)));
+
+	let actions = rule(
+		r#"
+			volume = 40
+			mute = true
+			target = "alsa_output.usb-hdmi"
+			"#,
+	)
+	.actions(firefox, &entries, 150);
+	assert_eq!(
+		actions,
+		vec![
+			PulseAudioAction::SetVolume(ident(EntryType::SinkInput, 5), forty),
+			PulseAudioAction::MuteEntry(ident(EntryType::SinkInput, 5), true),
+			PulseAudioAction::MoveEntryToParent(
+				ident(EntryType::SinkInput, 5),
+				ident(EntryType::Sink, 1)
+			),
+		]
+	);
+
+	// by description the first one is found, it's already there
+	assert!(rule(r#"target = "hdmi""#)
+		.actions(firefox, &entries, 150)
+		.is_empty());
+	// already unmuted
+	assert!(rule("mute = false")
+		.actions(firefox, &entries, 150)
+		.is_empty());
+}
+
+#[test]
+fn rule_volume_is_clamped_to_the_maximum() {
+	let entries = collect_entries(vec![sink(0, "Speakers"), sink_input(5, "Firefox", 0)]);
+	let firefox = entries.get(&ident(EntryType::SinkInput, 5)).unwrap();
+
+	let mut max = volume();
+	max.set(2, Volume(percent_to_volume(150, 150)));
+
+	assert_eq!(
+		rule("volume = 60000").actions(firefox, &entries, 150),
+		vec![PulseAudioAction::SetVolume(
+			ident(EntryType::SinkInput, 5),
+			max
+		)]
+	);
+
+	// devices themselves aren't touched
+	let speakers = entries.get(&ident(EntryType::Sink, 0)).unwrap();
+	assert!(rule("volume = 40")
+		.actions(speakers, &entries, 150)
+		.is_empty());
+}
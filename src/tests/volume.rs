@@ -0,0 +1,79 @@
+use pulse::volume::{ChannelVolumes, Volume, VolumeDB, VolumeLinear};
+
+use crate::util::{channel_volume_to_percent, percent_to_volume, shift_volume, VolumeDisplay};
+
+const MAX: u16 = 150;
+
+#[test]
+fn volumes_are_shown_in_every_mode() {
+	let half = Volume(percent_to_volume(50, MAX));
+
+	assert_eq!(VolumeDisplay::Percent.format(half), "50");
+	assert_eq!(VolumeDisplay::Decibel.format(Volume::NORMAL), "0.0dB");
+	assert_eq!(VolumeDisplay::Decibel.format(Volume::MUTED), "-inf dB");
+	assert_eq!(VolumeDisplay::Linear.format(Volume::NORMAL), "1.00");
+	assert_eq!(VolumeDisplay::Raw.format(Volume::NORMAL), "65536");
+
+	assert_eq!(VolumeDisplay::from_name("raw"), Some(VolumeDisplay::Raw));
+	assert_eq!(VolumeDisplay::from_name("loud"), None);
+}
+
+#[test]
+fn typed_volumes_use_their_suffix_or_the_mode() {
+	let percent = VolumeDisplay::Percent;
+
+	assert_eq!(percent.parse("50", MAX), Some(percent_to_volume(50, MAX)));
+	assert_eq!(
+		percent.parse("-6dB", MAX),
+		Some(Volume::from(VolumeDB(-6.0)).0)
+	);
+	assert_eq!(percent.parse("-inf dB", MAX), Some(Volume::MUTED.0));
+	assert_eq!(
+		VolumeDisplay::Linear.parse("0.5", MAX),
+		Some(Volume::from(VolumeLinear(0.5)).0)
+	);
+	assert_eq!(VolumeDisplay::Raw.parse("32768", MAX), Some(32768));
+	assert_eq!(VolumeDisplay::Raw.parse("0.5", MAX), None);
+	assert_eq!(
+		VolumeDisplay::Decibel.parse("0", MAX),
+		Some(Volume::NORMAL.0)
+	);
+	assert_eq!(
+		VolumeDisplay::Decibel.parse("50%", MAX),
+		Some(percent_to_volume(50, MAX))
+	);
+
+	// capped at the maximum, whatever the unit
+	assert_eq!(
+		percent.parse("+20dB", MAX),
+		Some(percent_to_volume(150, MAX))
+	);
+	assert_eq!(percent.parse("120", 100), Some(Volume::NORMAL.0));
+	assert_eq!(
+		VolumeDisplay::Raw.parse("4294967295", MAX),
+		Some(percent_to_volume(150, MAX))
+	);
+	assert_eq!(percent.parse("loud", MAX), None);
+	assert_eq!(percent.parse("", MAX), None);
+}
+
+#[test]
+fn volume_changes_stop_at_the_maximum() {
+	assert_eq!(percent_to_volume(120, 100), Volume::NORMAL.0);
+	assert_eq!(
+		channel_volume_to_percent(Volume(percent_to_volume(250, 300))),
+		250
+	);
+
+	let mut volume = ChannelVolumes::default();
+	volume.set(2, Volume(percent_to_volume(98, MAX)));
+	shift_volume(&mut volume, 5, 100);
+	assert_eq!(volume.get()[0], Volume::NORMAL);
+
+	// set above the maximum by another program, it can go down but not up
+	volume.set(2, Volume(percent_to_volume(180, 200)));
+	shift_volume(&mut volume, 5, 100);
+	assert_eq!(channel_volume_to_percent(volume.get()[0]), 180);
+	shift_volume(&mut volume, -5, 100);
+	assert_eq!(channel_volume_to_percent(volume.get()[0]), 175);
+}
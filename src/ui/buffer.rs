@@ -142,6 +142,15 @@ impl Buffer {
 		Ok(())
 	}
 
+	// what's on the screen after the last draw_changes, one string per line
+	#[cfg(test)]
+	pub fn lines(&self) -> Vec<String> {
+		self.pixels
+			.chunks(self.width.max(1) as usize)
+			.map(|line| line.iter().map(|p| p.text.unwrap_or(' ')).collect())
+			.collect()
+	}
+
 	fn coord_to_xy(&self, coord: usize) -> (u16, u16) {
 		let y = (coord as f32 / self.width as f32).floor() as usize;
 		let x = coord - (y * self.width as usize);
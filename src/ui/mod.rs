@@ -17,6 +17,7 @@ use widgets::{BlockWidget, Widget};
 use crate::{
 	models::{PageType, RSState, Style, UIMode},
 	prelude::*,
+	util::filter_match,
 };
 
 pub async fn redraw<W: Write>(stdout: &mut W, state: &mut RSState) -> Result<()> {
@@ -27,6 +28,15 @@ pub async fn redraw<W: Write>(stdout: &mut W, state: &mut RSState) -> Result<()>
 	Ok(())
 }
 pub async fn make_changes(state: &mut RSState) -> Result<()> {
+	// entries scrolled into view haven't been given an area yet
+	if !state
+		.page_entries
+		.visible_range(state.ui.entries_area.height)
+		.eq(state.page_entries.visibility.iter().copied())
+	{
+		state.redraw.resize = true;
+	}
+
 	if state.redraw.resize {
 		state.ui.terminal_too_small = match resize(state) {
 			Ok(()) => false,
@@ -78,6 +88,12 @@ pub async fn make_changes(state: &mut RSState) -> Result<()> {
 			if let Some(entry) = state.entries.get_mut(&ident) {
 				entry.position = state.page_entries.lvls[*i];
 				entry.is_selected = state.page_entries.selected() == *i;
+				entry.is_marked = state.marked.contains(&ident);
+				entry.highlighted = if state.filter.value.is_empty() {
+					Vec::new()
+				} else {
+					filter_match(&state.filter.value, &entry.name).unwrap_or_default()
+				};
 
 				entry.render(&mut state.ui.buffer)?;
 			}
@@ -98,7 +114,8 @@ pub async fn make_changes(state: &mut RSState) -> Result<()> {
 					area.x,
 					area.y + area.height,
 					area.width,
-					state.ui.entries_area.height - area.y - area.height,
+					(state.ui.entries_area.y + state.ui.entries_area.height)
+						.saturating_sub(area.y + area.height),
 				);
 
 				state.ui.buffer.rect(bottom, ' ', Style::Normal);
@@ -106,6 +123,10 @@ pub async fn make_changes(state: &mut RSState) -> Result<()> {
 		}
 	}
 
+	if state.current_page == PageType::Log && (state.redraw.resize || state.redraw.entries) {
+		state.log.render(&mut state.ui.buffer)?;
+	}
+
 	if let Some(index) = state.redraw.peak_volume {
 		if state
 			.page_entries
@@ -116,16 +137,55 @@ pub async fn make_changes(state: &mut RSState) -> Result<()> {
 				.entries
 				.get_play_entry_mut(&state.page_entries.get(index).unwrap())
 			{
-				play.peak_volume_bar = play.peak_volume_bar.volume(play.peak);
+				play.peak_volume_bar
+					.set_meters(&play.meters, &play.channel_map);
 				play.peak_volume_bar.small_render(&mut state.ui.buffer)?;
 			}
 		}
 	}
 
+	// the previous config is still in use, so the mixer stays usable under it
+	if let Some(err) = &state.config_error {
+		let width = state.ui.buffer.width.saturating_sub(4) as usize;
+		let text = format!("Config file error: {}", err);
+
+		state.ui.buffer.string(
+			2,
+			state.ui.buffer.height - 2,
+			text.chars().take(width).collect(),
+			Style::Warning,
+		);
+	}
+
+	state.filter.is_editing = state.ui_mode == UIMode::Filter;
+	state.filter.render(&mut state.ui.buffer)?;
+
+	if let UIMode::PickEntries(device) = state.ui_mode {
+		let hint = device.hint();
+		let width = state.ui.buffer.width as usize;
+
+		if hint.len() + 4 < width {
+			state.ui.buffer.string(
+				(width - hint.len() - 2) as u16,
+				state.ui.buffer.height - 1,
+				hint.to_string(),
+				Style::Bold,
+			);
+		}
+	} else {
+		state.toast.render(&mut state.ui.buffer)?;
+	}
+
 	match state.ui_mode {
 		UIMode::Help => state.help.render(&mut state.ui.buffer)?,
 		UIMode::ContextMenu => state.context_menu.render(&mut state.ui.buffer)?,
 		UIMode::InputVolumeValue => state.input_exact_volume.render(&mut state.ui.buffer)?,
+		UIMode::Channels => state.channels.render(&mut state.ui.buffer)?,
+		UIMode::Details => state.details.render(&mut state.ui.buffer)?,
+		UIMode::Message => state.message.render(&mut state.ui.buffer)?,
+		UIMode::LoadModule | UIMode::NameNullSink => {
+			state.module_input.render(&mut state.ui.buffer)?
+		}
 		_ => {}
 	};
 
@@ -138,6 +198,8 @@ pub struct UI {
 	pub entries_area: Rect,
 	pub terminal_too_small: bool,
 	pub pages_names: Vec<String>,
+	// used instead of the terminal size when rendering without a terminal
+	pub fixed_size: Option<(u16, u16)>,
 }
 
 impl Default for UI {
@@ -151,13 +213,19 @@ impl Default for UI {
 				PageType::Output.to_string(),
 				PageType::Input.to_string(),
 				PageType::Cards.to_string(),
+				PageType::Modules.to_string(),
+				PageType::Log.to_string(),
 			],
+			fixed_size: None,
 		}
 	}
 }
 
 fn resize(state: &mut RSState) -> Result<()> {
-	let (x, y) = crossterm::terminal::size()?;
+	let (x, y) = match state.ui.fixed_size {
+		Some(size) => size,
+		None => crossterm::terminal::size()?,
+	};
 	state.ui.buffer.resize(x, y);
 
 	state.ui.border.resize(Rect::new(
@@ -170,10 +238,12 @@ fn resize(state: &mut RSState) -> Result<()> {
 	state.ui.entries_area = Rect::new(2, 2, state.ui.buffer.width - 4, state.ui.buffer.height - 4);
 	let mut entry_area = state.ui.entries_area;
 
-	for i in state
+	state.page_entries.visibility = state
 		.page_entries
 		.visible_range(state.ui.entries_area.height)
-	{
+		.collect();
+
+	for i in state.page_entries.visibility.clone() {
 		let ent = match state.entries.get_mut(&state.page_entries.get(i).unwrap()) {
 			Some(x) => x,
 			None => {
@@ -191,6 +261,8 @@ fn resize(state: &mut RSState) -> Result<()> {
 
 	state.context_menu.resize(state.ui.entries_area)?;
 
+	state.log.resize(state.ui.entries_area)?;
+
 	if state.ui_mode == UIMode::InputVolumeValue {
 		if let Some(ident) = &state.page_entries.get_selected() {
 			if let Some(play) = state.entries.get_play_entry(ident) {
@@ -201,31 +273,68 @@ fn resize(state: &mut RSState) -> Result<()> {
 
 	state.help.resize(state.ui.entries_area)?;
 
+	state.filter.resize(Rect::new(
+		2,
+		state.ui.buffer.height - 1,
+		state.ui.buffer.width - 4,
+		1,
+	))?;
+
+	state.toast.resize(Rect::new(
+		2,
+		state.ui.buffer.height - 1,
+		state.ui.buffer.width - 4,
+		1,
+	))?;
+
+	if state.ui_mode == UIMode::Channels {
+		state.channels.resize(state.ui.entries_area)?;
+	}
+
+	if state.ui_mode == UIMode::Details {
+		state.details.resize(state.ui.entries_area)?;
+	}
+
+	if state.ui_mode == UIMode::Message {
+		state.message.resize(state.ui.entries_area)?;
+	}
+
+	if let UIMode::LoadModule | UIMode::NameNullSink = state.ui_mode {
+		state.module_input.resize(state.ui.entries_area)?;
+	}
+
 	Ok(())
 }
 
 fn gen_page_names(state: &mut RSState) -> Pixels {
+	let current = i8::from(state.current_page) as usize;
+
 	if state.ui.buffer.width as usize
-		> 2 + state.ui.pages_names.iter().map(|p| p.len()).sum::<usize>() + 6
+		> 2 + state.ui.pages_names.iter().map(|p| p.len()).sum::<usize>()
+			+ 3 * (state.ui.pages_names.len() - 1)
 	{
 		let style = |i: usize| {
-			if i as i8 == state.current_page.into() {
+			if i == current {
 				Style::Bold
 			} else {
 				Style::Muted
 			}
 		};
 
-		Pixels::default()
-			.string(style(0), &state.ui.pages_names[0])
-			.string(Style::Muted, " / ")
-			.string(style(1), &state.ui.pages_names[1])
-			.string(Style::Muted, " / ")
-			.string(style(2), &state.ui.pages_names[2])
+		state
+			.ui
+			.pages_names
+			.iter()
+			.enumerate()
+			.fold(Pixels::default(), |pixels, (i, name)| {
+				let pixels = if i > 0 {
+					pixels.string(Style::Muted, " / ")
+				} else {
+					pixels
+				};
+				pixels.string(style(i), name)
+			})
 	} else {
-		Pixels::default().string(
-			Style::Bold,
-			&state.ui.pages_names[state.page_entries.selected()],
-		)
+		Pixels::default().string(Style::Bold, &state.ui.pages_names[current])
 	}
 }
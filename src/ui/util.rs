@@ -5,12 +5,16 @@ use crossterm::{cursor::Hide, execute};
 use crate::{entry::EntrySpaceLvl, ui::UIError};
 
 pub fn entry_height(lvl: EntrySpaceLvl) -> u16 {
-	if lvl == EntrySpaceLvl::Card {
-		1
-	} else if lvl == EntrySpaceLvl::ParentNoChildren || lvl == EntrySpaceLvl::LastChild {
-		4
-	} else {
-		3
+	match lvl {
+		EntrySpaceLvl::Card => 1,
+		// the last entry under a sink or source leaves an empty line below it
+		EntrySpaceLvl::ParentNoChildren
+		| EntrySpaceLvl::LastChild
+		| EntrySpaceLvl::ClientChild {
+			last: true,
+			parent_continues: false,
+		} => 4,
+		_ => 3,
 	}
 }
 
@@ -0,0 +1,177 @@
+use pulse::channelmap::Position;
+
+use super::{ToolWindowWidget, VolumeWidget, VolumeWidgetBorder, Widget};
+use crate::{
+	entry::PlayEntry,
+	prelude::*,
+	scrollable,
+	ui::{Buffer, Rect, Scrollable, Style, UIError},
+	util::channel_volume_to_percent,
+};
+
+#[derive(Clone)]
+pub struct ChannelsWidget {
+	pub window: ToolWindowWidget,
+	pub channels: Vec<(String, u16)>,
+	pub mute: bool,
+	pub locked: bool,
+	// in percent, the bars end there
+	pub max_volume: u16,
+	area: Rect,
+	selected: usize,
+}
+
+impl Default for ChannelsWidget {
+	fn default() -> Self {
+		Self {
+			window: ToolWindowWidget::default(),
+			channels: Vec::new(),
+			mute: false,
+			locked: false,
+			max_volume: 150,
+			area: Rect::default(),
+			selected: 0,
+		}
+	}
+}
+
+impl ChannelsWidget {
+	pub fn set(&mut self, play: &PlayEntry, max_volume: u16) {
+		self.channels = play
+			.channel_map
+			.get()
+			.iter()
+			.zip(play.volume.get())
+			.map(|(position, volume)| {
+				(channel_label(*position), channel_volume_to_percent(*volume))
+			})
+			.collect();
+		self.mute = play.mute;
+		self.max_volume = max_volume.max(1);
+
+		if self.selected >= self.channels.len() {
+			self.selected = 0;
+		}
+	}
+}
+
+impl Widget for ChannelsWidget {
+	fn resize(&mut self, area: Rect) -> Result<()> {
+		if area.height < 3 || area.width < 16 {
+			return Err(UIError::TerminalTooSmall.into());
+		}
+		self.window.padding.0 = if area.width < 24 { 1 } else { 3 };
+		self.window.padding.1 = if area.height < 8 { 1 } else { 2 };
+
+		self.window.inner_width = area.width / 2;
+		// one line per channel, an empty line and lock status
+		self.window.inner_height = self.channels.len() as u16 + 2;
+
+		self.window.resize(area)?;
+
+		self.area = Rect::new(
+			self.window.area.x + self.window.padding.0,
+			self.window.area.y + self.window.padding.1,
+			self.window.area.width - self.window.padding.0 * 2,
+			self.window.area.height - self.window.padding.1 * 2,
+		);
+
+		Ok(())
+	}
+
+	fn render(&mut self, buffer: &mut Buffer) -> Result<()> {
+		self.window.render(buffer)?;
+
+		for (y, i) in self.visible_range(self.area.height).enumerate() {
+			let (label, percent) = &self.channels[i];
+			let y = self.area.y + y as u16;
+
+			buffer.string(
+				self.area.x,
+				y,
+				format!("{:<4}", label),
+				if self.selected == i {
+					Style::Inverted
+				} else {
+					Style::Normal
+				},
+			);
+
+			VolumeWidget::default()
+				.set_area(Rect::new(self.area.x + 5, y, self.area.width - 10, 1))
+				.volume((*percent as f32 / self.max_volume as f32).min(1.0))
+				.mute(self.mute)
+				.border(VolumeWidgetBorder::Single)
+				.render(buffer)?;
+
+			buffer.string(
+				self.area.x + self.area.width - 4,
+				y,
+				format!("{:>4}", percent),
+				if *percent > 100 {
+					Style::Warning
+				} else {
+					Style::Normal
+				},
+			);
+		}
+
+		if self.area.height > self.channels.len() as u16 + 1 {
+			let text = if self.locked {
+				"channels locked"
+			} else {
+				"channels unlocked"
+			};
+
+			buffer.string(
+				self.area.x + self.area.width / 2 - text.len() as u16 / 2,
+				self.area.y + self.area.height - 1,
+				text.to_string(),
+				Style::Muted,
+			);
+		}
+
+		Ok(())
+	}
+}
+
+pub fn channel_label(position: Position) -> String {
+	match position {
+		Position::Mono => "M".to_string(),
+		Position::FrontLeft => "FL".to_string(),
+		Position::FrontRight => "FR".to_string(),
+		Position::FrontCenter => "FC".to_string(),
+		Position::RearCenter => "RC".to_string(),
+		Position::RearLeft => "RL".to_string(),
+		Position::RearRight => "RR".to_string(),
+		Position::Lfe => "LFE".to_string(),
+		Position::FrontLeftOfCenter => "FLC".to_string(),
+		Position::FrontRightOfCenter => "FRC".to_string(),
+		Position::SideLeft => "SL".to_string(),
+		Position::SideRight => "SR".to_string(),
+		_ => Position::to_string(position)
+			.map(|s| s.to_uppercase())
+			.unwrap_or_default(),
+	}
+}
+
+scrollable!(
+	ChannelsWidget,
+	fn selected(&self) -> usize {
+		self.selected
+	},
+	fn len(&self) -> usize {
+		self.channels.len()
+	},
+	fn set_selected(&mut self, selected: usize) -> bool {
+		if selected < self.len() {
+			self.selected = selected;
+			true
+		} else {
+			false
+		}
+	},
+	fn element_height(&self, _index: usize) -> u16 {
+		1
+	}
+);
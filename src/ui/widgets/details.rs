@@ -0,0 +1,189 @@
+use super::{ToolWindowWidget, Widget};
+use crate::{
+	entry::{Entry, EntryIdentifier, EntryType},
+	prelude::*,
+	scrollable,
+	ui::{Buffer, Rect, Scrollable, Style, UIError},
+};
+
+#[derive(Clone)]
+struct DetailsLine {
+	text: String,
+	header: bool,
+}
+
+// details and the whole proplist of a sink, source or stream,
+// the name of the entry stays on top while the rest scrolls
+#[derive(Clone)]
+pub struct DetailsWidget {
+	pub window: ToolWindowWidget,
+	pub entry_ident: EntryIdentifier,
+	title: String,
+	lines: Vec<DetailsLine>,
+	selected: usize,
+	area: Rect,
+}
+
+impl Default for DetailsWidget {
+	fn default() -> Self {
+		Self {
+			window: ToolWindowWidget::default(),
+			entry_ident: EntryIdentifier::new(EntryType::Sink, 0),
+			title: String::new(),
+			lines: Vec::new(),
+			selected: 0,
+			area: Rect::default(),
+		}
+	}
+}
+
+impl DetailsWidget {
+	pub fn new(entry: &Entry) -> Self {
+		let mut widget = Self::default();
+		widget.update(entry);
+
+		widget
+	}
+
+	// keeps the scroll position, so the panel can follow updates of the entry
+	pub fn update(&mut self, entry: &Entry) {
+		self.entry_ident = EntryIdentifier::new(entry.entry_type, entry.index);
+		self.title = entry.name.clone();
+		self.lines = Vec::new();
+
+		if let Some(play) = entry.entry_kind.play_entry() {
+			let width = play.details.iter().map(|(k, _)| k.len()).max().unwrap_or(0);
+			self.lines
+				.extend(play.details.iter().map(|(k, v)| DetailsLine {
+					text: format!("{:width$}  {}", k, v, width = width),
+					header: false,
+				}));
+
+			if !play.properties.is_empty() {
+				if !self.lines.is_empty() {
+					self.lines.push(DetailsLine {
+						text: String::new(),
+						header: false,
+					});
+				}
+				self.lines.push(DetailsLine {
+					text: "Properties".to_string(),
+					header: true,
+				});
+
+				let width = play.properties.keys().map(|k| k.len()).max().unwrap_or(0);
+				self.lines
+					.extend(play.properties.iter().map(|(k, v)| DetailsLine {
+						text: format!("{:width$}  {}", k, v, width = width),
+						header: false,
+					}));
+			}
+		}
+
+		if self.lines.is_empty() {
+			self.lines.push(DetailsLine {
+				text: "No details".to_string(),
+				header: true,
+			});
+		}
+
+		self.selected = self.selected.min(self.lines.len() - 1);
+	}
+}
+
+impl Widget for DetailsWidget {
+	fn resize(&mut self, area: Rect) -> Result<()> {
+		if area.height < 5 || area.width < 16 {
+			return Err(UIError::TerminalTooSmall.into());
+		}
+		self.window.padding.0 = if area.width < 24 { 1 } else { 3 };
+		self.window.padding.1 = if area.height < 10 { 1 } else { 2 };
+
+		self.window.inner_width = self
+			.lines
+			.iter()
+			.map(|l| &l.text)
+			.chain(std::iter::once(&self.title))
+			.map(|l| l.chars().count() as u16)
+			.max()
+			.unwrap_or(0);
+		// title, an empty line and the details
+		self.window.inner_height = self.lines.len() as u16 + 2;
+
+		self.window.resize(area)?;
+
+		self.area = Rect::new(
+			self.window.area.x + self.window.padding.0,
+			self.window.area.y + self.window.padding.1,
+			self.window.area.width - self.window.padding.0 * 2,
+			self.window.area.height - self.window.padding.1 * 2,
+		);
+
+		Ok(())
+	}
+
+	fn render(&mut self, buffer: &mut Buffer) -> Result<()> {
+		self.window.render(buffer)?;
+
+		let width = self.area.width as usize;
+
+		buffer.string(
+			self.area.x,
+			self.area.y,
+			self.title.chars().take(width).collect(),
+			Style::Bold,
+		);
+
+		let height = self.area.height.saturating_sub(2);
+		let (start, end) = self.visible_start_end(height);
+
+		for (i, line) in self.lines.iter().enumerate().skip(start).take(end - start) {
+			let style = if i == self.selected {
+				Style::Bold
+			} else if line.header {
+				Style::Muted
+			} else {
+				Style::Normal
+			};
+
+			buffer.string(
+				self.area.x,
+				self.area.y + 2 + (i - start) as u16,
+				line.text.chars().take(width).collect(),
+				style,
+			);
+		}
+
+		// arrows on the right border when there's more to scroll to
+		let x = self.window.area.x + self.window.area.width - 1;
+		if start != 0 {
+			buffer.string(x, self.area.y + 2, "▲".to_string(), Style::Normal);
+		}
+		if end != self.len() {
+			buffer.string(x, self.area.y + 1 + height, "▼".to_string(), Style::Normal);
+		}
+
+		Ok(())
+	}
+}
+
+scrollable!(
+	DetailsWidget,
+	fn selected(&self) -> usize {
+		self.selected
+	},
+	fn len(&self) -> usize {
+		self.lines.len()
+	},
+	fn set_selected(&mut self, selected: usize) -> bool {
+		if selected < self.lines.len() {
+			self.selected = selected;
+			true
+		} else {
+			false
+		}
+	},
+	fn element_height(&self, _index: usize) -> u16 {
+		1
+	}
+);
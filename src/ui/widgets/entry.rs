@@ -3,12 +3,17 @@ use std::cmp::min;
 use pulse::volume;
 
 use crate::{
-	entry::{CardEntry, Entry, EntryKind, EntrySpaceLvl, HiddenStatus, PlayEntry},
+	entry::{
+		CardEntry, ClientEntry, Entry, EntryKind, EntrySpaceLvl, HiddenStatus, ModuleEntry,
+		PlayEntry,
+	},
 	prelude::*,
 	ui::{
 		widgets::{VolumeWidgetBorder, Widget},
 		Buffer, Rect, Style, UIError,
 	},
+	util::VolumeDisplay,
+	VARIABLES,
 };
 
 impl Widget for Entry {
@@ -23,21 +28,40 @@ impl Widget for Entry {
 				play.resize(area)
 			}
 			EntryKind::CardEntry(card) => card.resize(area),
+			EntryKind::ModuleEntry(module) => module.resize(area),
+			EntryKind::ClientEntry(client) => client.resize(area),
 		}
 	}
 	fn render(&mut self, buffer: &mut Buffer) -> Result<()> {
 		match &mut self.entry_kind {
 			EntryKind::PlayEntry(play) => {
 				play.is_selected = self.is_selected;
+				play.is_marked = self.is_marked;
 				play.position = self.position;
+				play.highlighted = self.highlighted.clone();
+				play.max_volume = VARIABLES.read().unwrap().max_volume_of(self.entry_type);
 
 				play.render(buffer)
 			}
 			EntryKind::CardEntry(card) => {
 				card.is_selected = self.is_selected;
+				card.highlighted = self.highlighted.clone();
 
 				card.render(buffer)
 			}
+			EntryKind::ModuleEntry(module) => {
+				module.is_selected = self.is_selected;
+				module.highlighted = self.highlighted.clone();
+
+				module.render(buffer)
+			}
+			EntryKind::ClientEntry(client) => {
+				client.is_selected = self.is_selected;
+				client.is_marked = self.is_marked;
+				client.highlighted = self.highlighted.clone();
+
+				client.render(buffer, self.position)
+			}
 		}
 	}
 }
@@ -85,6 +109,7 @@ impl PlayEntry {
 		match self.position {
 			EntrySpaceLvl::Parent | EntrySpaceLvl::ParentNoChildren => 2,
 			EntrySpaceLvl::MidChild | EntrySpaceLvl::LastChild => 5,
+			EntrySpaceLvl::ClientChild { .. } => 9,
 			_ => 0,
 		}
 	}
@@ -118,24 +143,86 @@ impl Widget for CardEntry {
 			(&self.name[0..name_len]).to_string(),
 			name_style,
 		);
+		highlight(
+			buffer,
+			self.area.x,
+			self.area.y,
+			&self.name,
+			name_len,
+			&self.highlighted,
+		);
+
+		if let Some(profile) = self.selected_profile.and_then(|i| self.profiles.get(i)) {
+			let label = profile
+				.label()
+				.chars()
+				.take((self.area.width / 2).into())
+				.collect::<String>();
+			let label_len = label.chars().count();
 
-		if let Some(index) = self.selected_profile {
-			let profile_len = min(
-				self.profiles[index].description.len(),
-				(self.area.width / 2).into(),
+			buffer.string(
+				self.area.x + self.area.width - label_len as u16,
+				self.area.y,
+				label,
+				if profile.is_available() {
+					style
+				} else {
+					Style::Muted
+				},
 			);
+		}
+
+		Ok(())
+	}
+}
+impl Widget for ModuleEntry {
+	fn resize(&mut self, area: Rect) -> Result<()> {
+		self.area = area;
+		Ok(())
+	}
+
+	fn render(&mut self, buffer: &mut Buffer) -> Result<()> {
+		buffer.rect(self.area, ' ', Style::Normal);
+
+		let name_style = if self.is_selected {
+			Style::Inverted
+		} else {
+			Style::Normal
+		};
+
+		let width = self.area.width as usize;
+		let name = self.name.chars().take(width).collect::<String>();
+		let name_len = name.chars().count();
+
+		buffer.string(self.area.x, self.area.y, name, name_style);
+		highlight(
+			buffer,
+			self.area.x,
+			self.area.y,
+			&self.name,
+			name_len,
+			&self.highlighted,
+		);
+
+		if name_len + 1 < width {
+			let argument = self
+				.argument
+				.chars()
+				.take(width - name_len - 1)
+				.collect::<String>();
 
 			buffer.string(
-				self.area.x + self.area.width - profile_len as u16,
+				self.area.x + name_len as u16 + 1,
 				self.area.y,
-				(&self.profiles[index].description[0..profile_len]).to_string(),
-				style,
+				argument,
+				Style::Muted,
 			);
 		}
 
 		Ok(())
 	}
 }
+
 impl Widget for PlayEntry {
 	fn resize(&mut self, area: Rect) -> Result<()> {
 		self.area = area;
@@ -152,18 +239,21 @@ impl Widget for PlayEntry {
 		}
 
 		let y = match self.position {
-			EntrySpaceLvl::ParentNoChildren | EntrySpaceLvl::LastChild => {
-				self.area.y + self.area.height - 2
-			}
+			EntrySpaceLvl::ParentNoChildren
+			| EntrySpaceLvl::LastChild
+			| EntrySpaceLvl::ClientChild {
+				last: true,
+				parent_continues: false,
+			} => self.area.y + self.area.height - 2,
 			_ => self.area.y + self.area.height - 1,
 		};
 
-		self.peak_volume_bar = self.peak_volume_bar.set_area(Rect::new(
+		self.peak_volume_bar.area = Rect::new(
 			self.area.x + self.offset(),
 			y,
 			self.area.width - self.offset() - 1,
 			1,
-		));
+		);
 
 		Ok(())
 	}
@@ -187,8 +277,17 @@ impl Widget for PlayEntry {
 		};
 
 		let text_area = self.play_entry_text_area();
-		let short_name = self
-			.name
+		let mut name = self.name.clone();
+		if self.is_default {
+			name.push_str(" (default)");
+		}
+		if self.is_picked {
+			name.push_str(" (picked)");
+		}
+		if self.is_marked {
+			name.push_str(" (marked)");
+		}
+		let short_name = name
 			.chars()
 			.take(if text_area.width > 2 {
 				text_area.width as usize - 2
@@ -197,7 +296,16 @@ impl Widget for PlayEntry {
 			})
 			.collect::<String>();
 
+		let short_len = short_name.chars().count();
 		buffer.string(text_area.x, text_area.y, short_name, name_style);
+		highlight(
+			buffer,
+			text_area.x,
+			text_area.y,
+			&name,
+			short_len,
+			&self.highlighted,
+		);
 
 		let avg = self.volume.avg().0;
 		let base_delta = (volume::Volume::NORMAL.0 as f32 - volume::Volume::MUTED.0 as f32) / 100.0;
@@ -207,7 +315,7 @@ impl Widget for PlayEntry {
 			let volume_area = self.volume_bar.area;
 			self.volume_bar = self
 				.volume_bar
-				.volume(vol_percent as f32 / 150.0)
+				.volume((vol_percent as f32 / self.max_volume.max(1) as f32).min(1.0))
 				.mute(self.mute)
 				.border(VolumeWidgetBorder::Upper);
 
@@ -240,11 +348,15 @@ impl Widget for PlayEntry {
 			}
 		}
 
-		let vol_perc = format!("  {}", vol_percent);
-		let vol_perc = String::from(&vol_perc[vol_perc.len() - 3..vol_perc.len()]);
-		let vol_db = self.volume.avg().print_db();
+		// the display mode on the right, dB on the left unless they'd be the same
+		let display = VARIABLES.read().unwrap().volume_display;
+		let vol_perc = format!("{:>3}", display.format(self.volume.avg()));
+		let vol_db = match display {
+			VolumeDisplay::Decibel => format!("{}%", vol_percent),
+			_ => self.volume.avg().print_db(),
+		};
 
-		if vol_db.len() + vol_perc.len() <= text_area.width as usize + 3 {
+		if vol_db.len() + vol_perc.len() + 3 <= text_area.width as usize {
 			let vol_str = format!(
 				"{}{}{}",
 				vol_db,
@@ -254,9 +366,18 @@ impl Widget for PlayEntry {
 				vol_perc
 			);
 
+			// a channel above 100% may clip
+			let style = if self.volume.max() > volume::Volume::NORMAL {
+				Style::Warning
+			} else {
+				style
+			};
+
 			buffer.string(text_area.x + 1, text_area.y + 1, vol_str, style);
 		}
 
+		self.peak_volume_bar
+			.set_meters(&self.meters, &self.channel_map);
 		self.peak_volume_bar.mute = self.mute;
 		self.peak_volume_bar.render(buffer)?;
 
@@ -285,9 +406,107 @@ impl Widget for PlayEntry {
 				buffer.string(self.area.x, self.area.y + 1, "│".to_string(), style);
 				buffer.string(self.area.x, self.area.y + 2, "└───".to_string(), style);
 			}
+			EntrySpaceLvl::ClientChild {
+				last,
+				parent_continues,
+			} => {
+				if parent_continues {
+					for y in 0..3 {
+						buffer.string(self.area.x, self.area.y + y, "│".to_string(), Style::Normal);
+					}
+				}
+				let branch = if last { "└───" } else { "├───" };
+				buffer.string(self.area.x + 4, self.area.y, "│".to_string(), style);
+				buffer.string(self.area.x + 4, self.area.y + 1, "│".to_string(), style);
+				buffer.string(self.area.x + 4, self.area.y + 2, branch.to_string(), style);
+			}
 			_ => {}
 		};
 
 		Ok(())
 	}
 }
+
+impl ClientEntry {
+	fn resize(&mut self, area: Rect) -> Result<()> {
+		self.area = area;
+		Ok(())
+	}
+
+	// drawn like a child of its sink or source, with the group hanging below it
+	fn render(&mut self, buffer: &mut Buffer, position: EntrySpaceLvl) -> Result<()> {
+		if self.area.width < 10 || self.area.height < 3 {
+			return Err(UIError::TerminalTooSmall.into());
+		}
+
+		buffer.rect(self.area, ' ', Style::Normal);
+
+		let style = if self.is_selected {
+			Style::Bold
+		} else {
+			Style::Normal
+		};
+		let name_style = if self.is_selected {
+			Style::Inverted
+		} else {
+			Style::Normal
+		};
+
+		let x = self.area.x + 5;
+		let width = (self.area.width - 5) as usize;
+
+		let mut name = self.name.clone();
+		if self.mute {
+			name.push_str(" (muted)");
+		}
+		if self.is_marked {
+			name.push_str(" (marked)");
+		}
+		let short_name = name.chars().take(width).collect::<String>();
+		let short_len = short_name.chars().count();
+
+		buffer.string(x, self.area.y, short_name, name_style);
+		highlight(buffer, x, self.area.y, &name, short_len, &self.highlighted);
+
+		// without streams below, while it's being moved
+		let (corner, branch) = if self.streams == 0 {
+			(position == EntrySpaceLvl::LastChild, "")
+		} else {
+			buffer.string(
+				x + 1,
+				self.area.y + 1,
+				format!("{} streams", self.streams)
+					.chars()
+					.take(width - 1)
+					.collect(),
+				Style::Muted,
+			);
+
+			(self.last, "┐")
+		};
+		let corner = if corner {
+			"└───"
+		} else {
+			"├───"
+		};
+		buffer.string(self.area.x, self.area.y, "│".to_string(), style);
+		buffer.string(self.area.x, self.area.y + 1, "│".to_string(), style);
+		buffer.string(
+			self.area.x,
+			self.area.y + 2,
+			format!("{}{}", corner, branch),
+			style,
+		);
+
+		Ok(())
+	}
+}
+
+// draws characters of `text` matching the filter over what's already there
+fn highlight(buffer: &mut Buffer, x: u16, y: u16, text: &str, len: usize, indexes: &[usize]) {
+	for (i, c) in text.chars().take(len).enumerate() {
+		if indexes.contains(&i) {
+			buffer.string(x + i as u16, y, c.to_string(), Style::Orange);
+		}
+	}
+}
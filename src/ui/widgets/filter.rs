@@ -0,0 +1,60 @@
+use super::Widget;
+use crate::{
+	models::Style,
+	prelude::*,
+	ui::{Buffer, Rect},
+};
+
+// filter input, drawn over the bottom border
+#[derive(Clone)]
+pub struct FilterWidget {
+	pub value: String,
+	pub cursor: u8,
+	pub is_editing: bool,
+	area: Rect,
+}
+
+impl Default for FilterWidget {
+	fn default() -> Self {
+		Self {
+			value: String::new(),
+			cursor: 0,
+			is_editing: false,
+			area: Rect::default(),
+		}
+	}
+}
+
+impl Widget for FilterWidget {
+	fn resize(&mut self, area: Rect) -> Result<()> {
+		self.area = area;
+		Ok(())
+	}
+
+	fn render(&mut self, buffer: &mut Buffer) -> Result<()> {
+		if self.value.is_empty() && !self.is_editing {
+			return Ok(());
+		}
+
+		let text = format!(" /{} ", self.value)
+			.chars()
+			.take(self.area.width as usize)
+			.collect::<String>();
+
+		buffer.string(self.area.x, self.area.y, text, Style::Bold);
+
+		let cursor_x = self.cursor as u16 + 2;
+		if self.is_editing && cursor_x < self.area.width {
+			let c = self.value.chars().nth(self.cursor as usize).unwrap_or(' ');
+
+			buffer.string(
+				self.area.x + cursor_x,
+				self.area.y,
+				c.to_string(),
+				Style::Inverted,
+			);
+		}
+
+		Ok(())
+	}
+}
@@ -0,0 +1,132 @@
+use super::Widget;
+use crate::{
+	models::{EventLog, LogFilter},
+	prelude::*,
+	scrollable,
+	ui::{Buffer, Rect, Scrollable, Style},
+	util::{filter_match, local_time},
+};
+
+// events of the server, newest at the bottom. follows new events
+// unless scrolled up from the last one
+#[derive(Clone)]
+pub struct LogWidget {
+	pub filter: LogFilter,
+	lines: Vec<String>,
+	selected: usize,
+	area: Rect,
+}
+
+impl Default for LogWidget {
+	fn default() -> Self {
+		Self {
+			filter: LogFilter::All,
+			lines: Vec::new(),
+			selected: 0,
+			area: Rect::default(),
+		}
+	}
+}
+
+impl LogWidget {
+	// `text` is the value of the filter input, matched against whole lines
+	pub fn update(&mut self, events: &EventLog, text: &str) {
+		let follow = self.selected + 1 >= self.lines.len();
+
+		self.lines = events
+			.iter()
+			.filter(|e| self.filter.matches(e))
+			.map(|e| match e.ident {
+				Some(ident) => format!(
+					"{}  {:7}  {:13}  {:>5}  {}",
+					local_time(e.time),
+					e.kind.name(),
+					ident.entry_type.name(),
+					format!("#{}", ident.index),
+					e.name
+				),
+				None => format!("{}  {}", local_time(e.time), e.text()),
+			})
+			.filter(|l| text.is_empty() || filter_match(text, l).is_some())
+			.collect();
+
+		if follow || self.selected >= self.lines.len() {
+			self.selected = self.lines.len().saturating_sub(1);
+		}
+	}
+}
+
+impl Widget for LogWidget {
+	fn resize(&mut self, area: Rect) -> Result<()> {
+		self.area = area;
+		Ok(())
+	}
+
+	fn render(&mut self, buffer: &mut Buffer) -> Result<()> {
+		buffer.rect(self.area, ' ', Style::Normal);
+
+		let width = self.area.width as usize;
+
+		buffer.string(
+			self.area.x,
+			self.area.y,
+			format!("Showing {}", self.filter.name())
+				.chars()
+				.take(width)
+				.collect(),
+			Style::Muted,
+		);
+
+		if self.lines.is_empty() {
+			buffer.string(
+				self.area.x,
+				self.area.y + 2,
+				"No events yet".chars().take(width).collect(),
+				Style::Normal,
+			);
+
+			return Ok(());
+		}
+
+		let height = self.area.height.saturating_sub(2);
+		let (start, end) = self.visible_start_end(height);
+
+		for (i, line) in self.lines.iter().enumerate().skip(start).take(end - start) {
+			let style = if i == self.selected {
+				Style::Bold
+			} else {
+				Style::Normal
+			};
+
+			buffer.string(
+				self.area.x,
+				self.area.y + 2 + (i - start) as u16,
+				line.chars().take(width).collect(),
+				style,
+			);
+		}
+
+		Ok(())
+	}
+}
+
+scrollable!(
+	LogWidget,
+	fn selected(&self) -> usize {
+		self.selected
+	},
+	fn len(&self) -> usize {
+		self.lines.len()
+	},
+	fn set_selected(&mut self, selected: usize) -> bool {
+		if selected < self.lines.len() {
+			self.selected = selected;
+			true
+		} else {
+			false
+		}
+	},
+	fn element_height(&self, _index: usize) -> u16 {
+		1
+	}
+);
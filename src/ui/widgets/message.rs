@@ -0,0 +1,79 @@
+use super::{ToolWindowWidget, Widget};
+use crate::{
+	prelude::*,
+	ui::{Buffer, Rect, Style, UIError},
+};
+
+// popup with a title and a few lines of text, closed with confirm or escape
+#[derive(Clone)]
+pub struct MessageWidget {
+	pub window: ToolWindowWidget,
+	pub title: String,
+	pub lines: Vec<String>,
+	area: Rect,
+}
+
+impl Default for MessageWidget {
+	fn default() -> Self {
+		Self {
+			window: ToolWindowWidget::default(),
+			title: String::new(),
+			lines: Vec::new(),
+			area: Rect::default(),
+		}
+	}
+}
+
+impl Widget for MessageWidget {
+	fn resize(&mut self, area: Rect) -> Result<()> {
+		if area.height < 3 || area.width < 16 {
+			return Err(UIError::TerminalTooSmall.into());
+		}
+		self.window.padding.0 = if area.width < 24 { 1 } else { 3 };
+		self.window.padding.1 = if area.height < 8 { 1 } else { 2 };
+
+		self.window.inner_width = self
+			.lines
+			.iter()
+			.chain(std::iter::once(&self.title))
+			.map(|l| l.chars().count() as u16)
+			.max()
+			.unwrap_or(0);
+		// title, an empty line and the message
+		self.window.inner_height = self.lines.len() as u16 + 2;
+
+		self.window.resize(area)?;
+
+		self.area = Rect::new(
+			self.window.area.x + self.window.padding.0,
+			self.window.area.y + self.window.padding.1,
+			self.window.area.width - self.window.padding.0 * 2,
+			self.window.area.height - self.window.padding.1 * 2,
+		);
+
+		Ok(())
+	}
+
+	fn render(&mut self, buffer: &mut Buffer) -> Result<()> {
+		self.window.render(buffer)?;
+
+		let width = self.area.width as usize;
+		let empty = String::new();
+
+		let lines = std::iter::once((&self.title, Style::Bold))
+			.chain(std::iter::once((&empty, Style::Normal)))
+			.chain(self.lines.iter().map(|l| (l, Style::Normal)))
+			.take(self.area.height as usize);
+
+		for (y, (line, style)) in lines.enumerate() {
+			buffer.string(
+				self.area.x,
+				self.area.y + y as u16,
+				line.chars().take(width).collect(),
+				style,
+			);
+		}
+
+		Ok(())
+	}
+}
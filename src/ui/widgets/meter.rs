@@ -0,0 +1,209 @@
+use pulse::channelmap::Map;
+
+use super::{channel_label, VolumeWidgetBorder, Widget};
+use crate::{
+	models::LevelMeter,
+	prelude::*,
+	ui::{Buffer, Pixel, Rect, Style, UIError},
+	VARIABLES,
+};
+
+// where the meter turns orange and red, in dBFS
+const ORANGE_FROM: f32 = -18.0;
+const RED_FROM: f32 = -6.0;
+// dB between the numbers of the scale
+const SCALE_STEP: i32 = 12;
+
+// level meter of an entry, the cells are spaced evenly in dB from the floor to 0 dBFS
+// average is drawn filled, the peak above it hollow and the held peak as a marker,
+// the empty part shows the scale, monitors recording every channel get a bar per channel side by side
+#[derive(Clone, PartialEq, Debug)]
+pub struct MeterWidget {
+	pub meters: Vec<LevelMeter>,
+	pub labels: Vec<String>,
+	pub floor: f32,
+	pub border: VolumeWidgetBorder,
+	pub area: Rect,
+	pub mute: bool,
+}
+
+impl Default for MeterWidget {
+	fn default() -> Self {
+		Self {
+			meters: Vec::new(),
+			labels: Vec::new(),
+			floor: -60.0,
+			border: VolumeWidgetBorder::Single,
+			area: Rect::default(),
+			mute: false,
+		}
+	}
+}
+
+impl MeterWidget {
+	pub fn set_meters(&mut self, meters: &[LevelMeter], channel_map: &Map) {
+		self.meters = meters.to_vec();
+		self.floor = VARIABLES.read().unwrap().meter.floor;
+
+		self.labels = if meters.len() > 1 && meters.len() == channel_map.len() as usize {
+			channel_map
+				.get()
+				.iter()
+				.map(|p| channel_label(*p))
+				.collect()
+		} else {
+			Vec::new()
+		};
+	}
+
+	// only the inside, used on every update of the levels
+	pub fn small_render(&mut self, buffer: &mut Buffer) -> Result<()> {
+		let width = self.area.width.saturating_sub(2);
+		let count = self.meters.len().max(1) as u16;
+		// bars are separated by a space
+		let bar_width = ((width + 1) / count).saturating_sub(1);
+
+		let pixels = if count == 1 || bar_width < 2 {
+			self.bar(&self.loudest(), width)
+		} else {
+			let label_width = self.labels.iter().map(|l| l.len()).max().unwrap_or(0) as u16;
+			let with_labels = label_width > 0 && bar_width >= label_width + 5;
+
+			let mut pixels = Vec::new();
+			for (i, meter) in self.meters.iter().enumerate() {
+				if i != 0 {
+					pixels.push(Pixel {
+						text: Some(' '),
+						style: Style::Normal,
+					});
+				}
+
+				if with_labels {
+					let label = format!("{:w$} ", self.labels[i], w = label_width as usize);
+					pixels.extend(label.chars().map(|c| Pixel {
+						text: Some(c),
+						style: Style::Muted,
+					}));
+					pixels.extend(self.bar(meter, bar_width - label_width - 1));
+				} else {
+					pixels.extend(self.bar(meter, bar_width));
+				}
+			}
+			pixels.resize(
+				width as usize,
+				Pixel {
+					text: Some(' '),
+					style: Style::Normal,
+				},
+			);
+
+			pixels
+		};
+
+		buffer.pixels(self.area.x + 1, self.area.y, &pixels.into());
+
+		Ok(())
+	}
+
+	// every channel at once, when there's no room for a bar per channel
+	fn loudest(&self) -> LevelMeter {
+		let mut loudest = LevelMeter::default();
+		for meter in &self.meters {
+			loudest.peak = loudest.peak.max(meter.peak);
+			loudest.average = loudest.average.max(meter.average);
+			loudest.hold = loudest.hold.max(meter.hold);
+		}
+
+		loudest
+	}
+
+	fn bar(&self, meter: &LevelMeter, width: u16) -> Vec<Pixel> {
+		let cells = |level| (LevelMeter::fraction(level, self.floor) * width as f32).floor() as u16;
+		let average = cells(meter.average);
+		let peak = cells(meter.peak).max(average);
+		let hold = cells(meter.hold);
+
+		let mut pixels: Vec<Pixel> = (0..width)
+			.map(|i| Pixel {
+				text: if i < average {
+					Some('▮')
+				} else if i < peak {
+					Some('▯')
+				} else if hold > 0 && i == hold - 1 {
+					Some('|')
+				} else {
+					Some('-')
+				},
+				style: self.style(i, width),
+			})
+			.collect();
+
+		self.scale(&mut pixels);
+
+		pixels
+	}
+
+	// dB below full scale every SCALE_STEP, each number ends where its level is,
+	// it's left out where the bar reaches it or it would touch its neighbour
+	fn scale(&self, pixels: &mut [Pixel]) {
+		let width = pixels.len();
+		let steps = (-self.floor / SCALE_STEP as f32).ceil() as i32;
+		let mut free_until = width;
+
+		for step in 1..steps {
+			let level = -step * SCALE_STEP;
+			let label = (-level).to_string();
+			let end =
+				(LevelMeter::fraction(level as f32, self.floor) * width as f32).round() as usize;
+
+			let start = match end.checked_sub(label.len() + 1) {
+				Some(before) if end < free_until => before + 1,
+				_ => continue,
+			};
+			// a dash is kept before the number
+			if pixels[start - 1..end].iter().any(|p| p.text != Some('-')) {
+				continue;
+			}
+
+			for (pixel, c) in pixels[start..end].iter_mut().zip(label.chars()) {
+				pixel.text = Some(c);
+			}
+			free_until = start - 1;
+		}
+	}
+
+	fn style(&self, cell: u16, width: u16) -> Style {
+		if self.mute {
+			return Style::Muted;
+		}
+
+		// level at the right edge of the cell
+		let level = self.floor - self.floor * (cell + 1) as f32 / width as f32;
+
+		if level <= ORANGE_FROM {
+			Style::Green
+		} else if level <= RED_FROM {
+			Style::Orange
+		} else {
+			Style::Red
+		}
+	}
+}
+
+impl Widget for MeterWidget {
+	fn resize(&mut self, area: Rect) -> Result<()> {
+		if area.width < 3 || area.height < 1 {
+			return Err(UIError::TerminalTooSmall.into());
+		}
+
+		self.area = area;
+
+		Ok(())
+	}
+
+	fn render(&mut self, buffer: &mut Buffer) -> Result<()> {
+		self.border.render(buffer, &self.area);
+
+		self.small_render(buffer)
+	}
+}
@@ -1,14 +1,30 @@
 mod block;
+mod channels;
 mod context_menu;
+mod details;
 mod entry;
+mod filter;
 mod help;
+mod log;
+mod message;
+mod meter;
+mod module_input;
+mod toast;
 mod tool_window;
 mod volume;
 mod volume_input;
 mod warning_text;
 
 pub use block::BlockWidget;
+pub use channels::{channel_label, ChannelsWidget};
+pub use details::DetailsWidget;
+pub use filter::FilterWidget;
 pub use help::HelpWidget;
+pub use log::LogWidget;
+pub use message::MessageWidget;
+pub use meter::MeterWidget;
+pub use module_input::ModuleInputWidget;
+pub use toast::ToastWidget;
 pub use tool_window::ToolWindowWidget;
 pub use volume::{VolumeWidget, VolumeWidgetBorder};
 pub use volume_input::VolumeInputWidget;
@@ -0,0 +1,97 @@
+use super::{ToolWindowWidget, Widget};
+use crate::{
+	prelude::*,
+	ui::{Buffer, Rect, Style, UIError},
+};
+
+// dialog for loading a module, the value is the module name and its arguments,
+// also used for naming a new null sink
+#[derive(Clone)]
+pub struct ModuleInputWidget {
+	pub window: ToolWindowWidget,
+	pub title: &'static str,
+	pub hint: &'static str,
+	pub value: String,
+	pub cursor: u8,
+	area: Rect,
+}
+
+impl Default for ModuleInputWidget {
+	fn default() -> Self {
+		Self {
+			window: ToolWindowWidget::default(),
+			title: "",
+			hint: "",
+			value: String::new(),
+			cursor: 0,
+			area: Rect::default(),
+		}
+	}
+}
+
+impl Widget for ModuleInputWidget {
+	fn resize(&mut self, area: Rect) -> Result<()> {
+		if area.height < 5 || area.width < 16 {
+			return Err(UIError::TerminalTooSmall.into());
+		}
+		self.window.padding.0 = if area.width < 24 { 1 } else { 3 };
+		self.window.padding.1 = if area.height < 9 { 1 } else { 2 };
+
+		self.window.inner_width = area.width * 2 / 3;
+		// title, an empty line, the input, an empty line and an example
+		self.window.inner_height = 5;
+
+		self.window.resize(area)?;
+
+		self.area = Rect::new(
+			self.window.area.x + self.window.padding.0,
+			self.window.area.y + self.window.padding.1,
+			self.window.area.width - self.window.padding.0 * 2,
+			self.window.area.height - self.window.padding.1 * 2,
+		);
+
+		Ok(())
+	}
+
+	fn render(&mut self, buffer: &mut Buffer) -> Result<()> {
+		self.window.render(buffer)?;
+
+		let width = self.area.width as usize;
+
+		buffer.string(
+			self.area.x,
+			self.area.y,
+			self.title.chars().take(width).collect(),
+			Style::Bold,
+		);
+
+		// scrolled so that the cursor is always visible
+		let skip = (self.cursor as usize + 1).saturating_sub(width);
+		let value = self
+			.value
+			.chars()
+			.skip(skip)
+			.take(width)
+			.collect::<String>();
+		buffer.string(self.area.x, self.area.y + 2, value, Style::Normal);
+
+		let c = self.value.chars().nth(self.cursor as usize).unwrap_or(' ');
+		buffer.string(
+			self.area.x + (self.cursor as usize - skip) as u16,
+			self.area.y + 2,
+			c.to_string(),
+			Style::Inverted,
+		);
+
+		if self.area.height > 4 {
+			buffer.string(
+				self.area.x,
+				self.area.y + 4,
+				self.hint.chars().take(width).collect(),
+				Style::Muted,
+			);
+		}
+
+		Ok(())
+	}
+}
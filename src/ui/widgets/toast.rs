@@ -0,0 +1,48 @@
+use super::Widget;
+use crate::{
+	models::Style,
+	prelude::*,
+	ui::{Buffer, Rect},
+};
+
+// the latest event, drawn over the bottom border for a few seconds
+#[derive(Clone)]
+pub struct ToastWidget {
+	pub text: String,
+	// number of toasts shown, identifies the current one
+	pub id: u64,
+	area: Rect,
+}
+
+impl Default for ToastWidget {
+	fn default() -> Self {
+		Self {
+			text: String::new(),
+			id: 0,
+			area: Rect::default(),
+		}
+	}
+}
+
+impl Widget for ToastWidget {
+	fn resize(&mut self, area: Rect) -> Result<()> {
+		self.area = area;
+		Ok(())
+	}
+
+	fn render(&mut self, buffer: &mut Buffer) -> Result<()> {
+		if self.text.is_empty() {
+			return Ok(());
+		}
+
+		let text = format!(" {} ", self.text)
+			.chars()
+			.take(self.area.width as usize)
+			.collect::<String>();
+		let x = self.area.x + self.area.width - text.chars().count() as u16;
+
+		buffer.string(x, self.area.y, text, Style::Bold);
+
+		Ok(())
+	}
+}
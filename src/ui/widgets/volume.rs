@@ -128,7 +128,7 @@ impl Widget for VolumeWidget {
 }
 
 impl VolumeWidgetBorder {
-	fn render(&mut self, buffer: &mut Buffer, area: &Rect) {
+	pub fn render(&mut self, buffer: &mut Buffer, area: &Rect) {
 		if *self == VolumeWidgetBorder::None {
 			return;
 		}
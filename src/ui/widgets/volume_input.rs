@@ -24,7 +24,8 @@ impl Default for VolumeInputWidget {
 
 impl Widget for VolumeInputWidget {
 	fn resize(&mut self, area: Rect) -> Result<()> {
-		let area = Rect::new(area.x + area.width / 2 - 3, area.y, 7, 3);
+		// wide enough for values in dB
+		let area = Rect::new(area.x + area.width / 2 - 5, area.y, 11, 3);
 		self.window.resize(area)?;
 		Ok(())
 	}
@@ -33,7 +34,7 @@ impl Widget for VolumeInputWidget {
 		self.window.render(buffer)?;
 
 		buffer.string(
-			self.window.area.x + 3 - self.value.len() as u16 / 2,
+			self.window.area.x + (self.window.area.width - self.value.len() as u16) / 2,
 			self.window.area.y + 1,
 			self.value.clone(),
 			Style::Normal,
@@ -1,26 +1,151 @@
+use std::time::SystemTime;
+
+use chrono::{DateTime, Local};
 use pulse::volume;
 
 pub fn volume_to_percent(volume: volume::ChannelVolumes) -> u16 {
-	let avg = volume.avg().0;
+	channel_volume_to_percent(volume.avg())
+}
 
+pub fn channel_volume_to_percent(volume: volume::Volume) -> u16 {
 	let base_delta = (volume::Volume::NORMAL.0 as f32 - volume::Volume::MUTED.0 as f32) / 100.0;
 
-	((avg - volume::Volume::MUTED.0) as f32 / base_delta).round() as u16
+	((volume.0 - volume::Volume::MUTED.0) as f32 / base_delta).round() as u16
+}
+
+// moves every channel by the same number of percentage points,
+// so the differences between channels stay the same
+pub fn shift_volume(volume: &mut volume::ChannelVolumes, how_much: i16, max_percent: u16) {
+	for v in volume.get_mut() {
+		*v = volume::Volume(shift_channel_volume(*v, how_much, max_percent));
+	}
 }
 
-pub fn percent_to_volume(target_percent: i16) -> u32 {
+// a channel already above the maximum (set by another program) isn't raised, nor cut down
+pub fn shift_channel_volume(volume: volume::Volume, how_much: i16, max_percent: u16) -> u32 {
+	let percent = channel_volume_to_percent(volume);
+
+	percent_to_volume(
+		percent.min(i16::MAX as u16) as i16 + how_much,
+		max_percent.max(percent),
+	)
+}
+
+// percent of the normal volume, capped at max_percent
+pub fn percent_to_volume(target_percent: i16, max_percent: u16) -> u32 {
 	let base_delta = (volume::Volume::NORMAL.0 as f32 - volume::Volume::MUTED.0 as f32) / 100.0;
 
-	if target_percent < 0 {
-		volume::Volume::MUTED.0
-	} else if target_percent == 100 {
+	let target_percent = (target_percent.max(0) as u16).min(max_percent);
+
+	if target_percent == 100 {
 		volume::Volume::NORMAL.0
-	} else if target_percent >= 150 {
-		(volume::Volume::NORMAL.0 as f32 * 1.5) as u32
 	} else if target_percent < 100 {
 		volume::Volume::MUTED.0 + target_percent as u32 * base_delta as u32
 	} else {
-		volume::Volume::NORMAL.0 + (target_percent - 100) as u32 * base_delta as u32
+		(volume::Volume::NORMAL.0 + (target_percent - 100) as u32 * base_delta as u32)
+			.min(volume::Volume::MAX.0)
+	}
+}
+
+// how volumes are shown in entries and typed into the exact volume input
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum VolumeDisplay {
+	// percent of the normal volume, the cubic scale pulseaudio uses for sliders
+	Percent,
+	Decibel,
+	// amplitude factor, 1.0 is the normal volume
+	Linear,
+	// pa_volume_t as pulseaudio stores it, 65536 is the normal volume
+	Raw,
+}
+
+impl VolumeDisplay {
+	pub fn from_name(name: &str) -> Option<Self> {
+		match &name.to_lowercase()[..] {
+			"percent" => Some(Self::Percent),
+			"db" => Some(Self::Decibel),
+			"linear" => Some(Self::Linear),
+			"raw" => Some(Self::Raw),
+			_ => None,
+		}
+	}
+
+	pub fn format(&self, volume: volume::Volume) -> String {
+		match self {
+			Self::Percent => channel_volume_to_percent(volume).to_string(),
+			Self::Decibel if volume.is_muted() => "-inf dB".to_string(),
+			Self::Decibel => format!("{:.1}dB", volume::VolumeDB::from(volume).0),
+			Self::Linear => format!("{:.2}", volume::VolumeLinear::from(volume).0),
+			Self::Raw => volume.0.to_string(),
+		}
+	}
+
+	// a "dB" or "%" suffix picks the unit, without one the value is read in this mode
+	pub fn parse(&self, value: &str, max_percent: u16) -> Option<u32> {
+		let value = value.trim().to_lowercase();
+
+		let (unit, number) = if let Some(n) = value.strip_suffix("db") {
+			(Self::Decibel, n)
+		} else if let Some(n) = value.strip_suffix('%') {
+			(Self::Percent, n)
+		} else {
+			(*self, &value[..])
+		};
+		let number = number.trim();
+
+		let volume = match unit {
+			Self::Percent => percent_to_volume(
+				number.parse::<u16>().ok()?.min(i16::MAX as u16) as i16,
+				max_percent,
+			),
+			Self::Decibel if number == "-inf" => volume::Volume::MUTED.0,
+			Self::Decibel => volume::Volume::from(volume::VolumeDB(number.parse::<f64>().ok()?)).0,
+			Self::Linear => {
+				volume::Volume::from(volume::VolumeLinear(number.parse::<f64>().ok()?.max(0.0))).0
+			}
+			Self::Raw => number.parse::<u32>().ok()?,
+		};
+
+		Some(volume.min(percent_to_volume(i16::MAX, max_percent)))
+	}
+}
+
+// indexes of the characters of `text` that match `pattern`, ignoring case,
+// either as one continuous part or, failing that, as a subsequence
+pub fn filter_match(pattern: &str, text: &str) -> Option<Vec<usize>> {
+	let eq = |a: &char, b: &char| a.to_lowercase().eq(b.to_lowercase());
+
+	let text = text.chars().collect::<Vec<char>>();
+	let pattern = pattern.chars().collect::<Vec<char>>();
+
+	if pattern.len() > text.len() {
+		return None;
+	}
+
+	if let Some(start) = (0..=text.len() - pattern.len())
+		.find(|&s| pattern.iter().zip(&text[s..]).all(|(p, t)| eq(p, t)))
+	{
+		return Some((start..start + pattern.len()).collect());
+	}
+
+	let mut indexes = Vec::new();
+	let mut pattern = pattern.iter().peekable();
+
+	for (i, c) in text.iter().enumerate() {
+		match pattern.peek() {
+			Some(p) if eq(p, c) => {
+				indexes.push(i);
+				pattern.next();
+			}
+			Some(_) => {}
+			None => break,
+		}
+	}
+
+	if pattern.peek().is_none() {
+		Some(indexes)
+	} else {
+		None
 	}
 }
 
@@ -66,3 +191,8 @@ macro_rules! warn {
         log::warn!("[{}] {}", LOGGING_MODULE, format!($($x),*));
     }
 }
+
+// hours, minutes and seconds of the given time, in the local timezone
+pub fn local_time(time: SystemTime) -> String {
+	DateTime::<Local>::from(time).format("%H:%M:%S").to_string()
+}